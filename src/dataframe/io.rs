@@ -2,6 +2,7 @@
 //! This module contains the io operators for dealing with DataFrames reading and writing.
 //!
 
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::path::Path;
 
@@ -29,6 +30,9 @@ pub struct Reader {
     quote: u8,
     has_headers: bool,
     header: Option<Vec<String>>,
+    datetime_format: Option<String>,
+    categorical_ratio: Option<f64>,
+    dtypes: HashMap<String, DType>,
 }
 
 /// DataFrame reading struct
@@ -52,6 +56,7 @@ pub struct Writer {
     terminator: csv::Terminator,
     quote: u8,
     has_headers: bool,
+    datetime_format: Option<String>,
 }
 
 impl Reader {
@@ -64,6 +69,9 @@ impl Reader {
             quote: b'"',
             has_headers: true,
             header: None,
+            datetime_format: None,
+            categorical_ratio: None,
+            dtypes: HashMap::new(),
         }
     }
 
@@ -102,6 +110,41 @@ impl Reader {
         rdr
     }
 
+    /// Set a format used to infer datetime columns, see [`DateTime::parse_from_str`];
+    /// ISO-8601 columns are always inferred.
+    pub fn datetime_format(self, format: &str) -> Self {
+        let mut rdr = self;
+        rdr.datetime_format = Some(format.to_string());
+        rdr
+    }
+
+    /// Read string columns as [`Categorical`] when the number of distinct values is at most
+    /// `max_ratio` of the number of non-null values; ie. `0.5` when each value repeats on average.
+    pub fn infer_categorical(self, max_ratio: f64) -> Self {
+        let mut rdr = self;
+        rdr.categorical_ratio = Some(max_ratio);
+        rdr
+    }
+
+    /// Read a column as `dtype` rather than inferring its type; ie. `DType::U64` for IDs
+    /// which don't fit in an `i32`. Reading fails if a field of the column can't be parsed.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let path = format!("{}/tests/data/basic_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    /// let df = Reader::new(&path).dtype("col2", DType::U8).read().unwrap();
+    ///
+    /// let col2: &Series<u8> = df.get_column("col2").unwrap();
+    /// assert_eq!(col2.sum(), 15);
+    /// ```
+    pub fn dtype(self, column: &str, dtype: DType) -> Self {
+        let mut rdr = self;
+        rdr.dtypes.insert(column.to_string(), dtype);
+        rdr
+    }
+
     /// Read a CSV file into a [`DataFrame`] where each column represents a Series
     /// supports automatic decompression of gzipped files if they end with `.gz`
     pub fn read(&self) -> Result<DataFrame<i32>, BlackJackError> {
@@ -166,23 +209,82 @@ impl Reader {
         // map headers to vectors containing it's fields in parallel and into
         // Series structs, parsing each field.
         // TODO: Parallelize this operation, parse && serialize columns in parallel, then add them.
-        let _ = headers
+        headers
             .into_iter()
             .zip(vecs)
             .map(|(header, vec)| {
-                let mut series = Series::from_vec(vec);
+                // Empty fields are read as null
+                let mut series = Series::from_options(
+                    vec.into_iter()
+                        .map(|field| if field.is_empty() { None } else { Some(field) })
+                        .collect(),
+                );
                 series.set_name(&header);
-                if let Ok(ser) = series.astype::<i32>() {
-                    df.add_column(ser).unwrap();
+                if let Some(dtype) = self.dtypes.get(&header) {
+                    self.add_column_as(&mut df, series, dtype)
+                } else if let Ok(ser) = series.astype::<i32>() {
+                    df.add_column(ser)
                 } else if let Ok(ser) = series.astype::<f32>() {
-                    df.add_column(ser).unwrap()
+                    df.add_column(ser)
+                } else if let Ok(ser) = series.astype::<bool>() {
+                    df.add_column(ser)
+                } else if let Ok(ser) = series.to_datetime(None) {
+                    df.add_column(ser)
+                } else if let Some(Ok(ser)) = self
+                    .datetime_format
+                    .as_ref()
+                    .map(|format| series.to_datetime(Some(format)))
+                {
+                    df.add_column(ser)
+                } else if let Some(ratio) = self.categorical_ratio {
+                    let categorical = series.to_categorical();
+                    if series.count() > 0
+                        && categorical.categories().len() as f64 <= ratio * series.count() as f64
+                    {
+                        df.add_categorical_column(categorical)
+                    } else {
+                        df.add_column(series)
+                    }
                 } else {
-                    df.add_column(series).unwrap()
+                    df.add_column(series)
                 }
             })
-            .collect::<Vec<()>>();
+            .collect::<Result<Vec<()>, BlackJackError>>()?;
         Ok(df)
     }
+
+    // Parse a column of raw fields into `dtype` and add it to the dataframe
+    fn add_column_as(
+        &self,
+        df: &mut DataFrame<i32>,
+        series: Series<String>,
+        dtype: &DType,
+    ) -> Result<(), BlackJackError> {
+        let err = |_| {
+            BlackJackError::ValueError(format!(
+                "Unable to read column '{}' as {:?}",
+                series.name().unwrap_or_default(),
+                dtype
+            ))
+        };
+        match dtype {
+            DType::F64 => df.add_column(series.astype::<f64>().map_err(err)?),
+            DType::I64 => df.add_column(series.astype::<i64>().map_err(err)?),
+            DType::F32 => df.add_column(series.astype::<f32>().map_err(err)?),
+            DType::I32 => df.add_column(series.astype::<i32>().map_err(err)?),
+            DType::I16 => df.add_column(series.astype::<i16>().map_err(err)?),
+            DType::I8 => df.add_column(series.astype::<i8>().map_err(err)?),
+            DType::U64 => df.add_column(series.astype::<u64>().map_err(err)?),
+            DType::U32 => df.add_column(series.astype::<u32>().map_err(err)?),
+            DType::U16 => df.add_column(series.astype::<u16>().map_err(err)?),
+            DType::U8 => df.add_column(series.astype::<u8>().map_err(err)?),
+            DType::BOOL => df.add_column(series.astype::<bool>().map_err(err)?),
+            DType::DATETIME => df.add_column(series.to_datetime(self.datetime_format.as_deref())?),
+            DType::DURATION => df.add_column(series.astype::<Duration>().map_err(err)?),
+            DType::CATEGORICAL => df.add_categorical_column(series.to_categorical()),
+            DType::STRING => df.add_column(series),
+        }
+    }
 }
 
 impl Writer {
@@ -194,6 +296,7 @@ impl Writer {
             terminator: csv::Terminator::CRLF,
             quote: b'"',
             has_headers: true,
+            datetime_format: None,
         }
     }
 
@@ -225,6 +328,14 @@ impl Writer {
         wtr
     }
 
+    /// Set the format used to write datetime columns, see [`DateTime::format`];
+    /// default is ISO-8601.
+    pub fn datetime_format(self, format: &str) -> Self {
+        let mut wtr = self;
+        wtr.datetime_format = Some(format.to_string());
+        wtr
+    }
+
     /// Write a dataframe to CSV, consumes self, and thus will not double memory whilst
     /// writing to CSV.
     pub fn write<I: PartialEq + PartialOrd + BlackJackData>(
@@ -259,7 +370,12 @@ impl Writer {
         let mut data = vec![];
         for col_name in df.data.keys() {
             let series_container = df.get_column_infer(col_name.as_str()).unwrap();
-            let string_vec = series_container.into_string_vec();
+            let string_vec = match (series_container, &self.datetime_format) {
+                (GenericSeriesContainer::DATETIME(series), Some(format)) => {
+                    series.strftime(format).into_vec()
+                }
+                (series_container, _) => series_container.into_string_vec(),
+            };
             data.push(string_vec);
         }
 
@@ -2,11 +2,16 @@
 //!
 //!
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
 use baggie::Baggie;
 use num::*;
 use serde::Deserialize;
 
 use crate::prelude::*;
+use crate::series::describe::DESCRIBE_STATS;
+use crate::series::hashing::duplicated_keys;
 
 pub mod dataframe_groupby;
 pub mod io;
@@ -93,6 +98,111 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         self.drop_positions(positions_to_drop.into_iter())
     }
 
+    /// Filter the dataframe in place, keeping only the rows where a boolean mask
+    /// is `true`. Null elements of the mask are treated as `false`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from(0..4)).is_ok());
+    ///
+    /// let mask = Series::from_vec(vec![true, false, true, true]);
+    /// df.filter_by_mask(&mask).unwrap();
+    ///
+    /// assert_eq!(df.len(), 3);
+    /// ```
+    pub fn filter_by_mask(&mut self, mask: &Series<bool>) -> Result<(), BlackJackError> {
+        if self.len() != mask.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has length: {}, cannot filter by mask of length: {}",
+                self.len(),
+                mask.len()
+            )));
+        }
+        let positions_to_drop = mask
+            .values
+            .iter()
+            .enumerate()
+            .filter(|(idx, keep)| !**keep || mask.is_null(*idx))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+
+        self.drop_positions(positions_to_drop.into_iter());
+        Ok(())
+    }
+
+    /// Drop the rows in place which repeat the values of another row in the `subset`
+    /// of columns, or in every column if `None`; keeping the occurrence chosen by `keep`.
+    /// See [`Series::drop_duplicates`].
+    ///
+    /// Returns a `ValueError` if a column of the subset doesn't exist.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 1, 2, 1])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec![5., 6., 5., 5.])).is_ok());
+    ///
+    /// df.drop_duplicates(None, Keep::Last).unwrap();
+    /// assert_eq!(df.index().values, vec![1, 2, 3]);
+    ///
+    /// df.drop_duplicates(Some(&["col_0"]), Keep::First).unwrap();
+    /// assert_eq!(df.index().values, vec![1, 2]);
+    /// ```
+    pub fn drop_duplicates(
+        &mut self,
+        subset: Option<&[&str]>,
+        keep: Keep,
+    ) -> Result<(), BlackJackError> {
+        let names: Vec<String> = match subset {
+            Some(subset) => subset.iter().map(|name| name.to_string()).collect(),
+            None => self.meta.iter().map(|meta| meta.name.clone()).collect(),
+        };
+
+        // Identify each row by the identifiers of its values in each column
+        let mut keys = vec![vec![]; self.len()];
+        for name in names {
+            let meta = self
+                .meta
+                .iter()
+                .find(|meta| meta.name == name)
+                .ok_or_else(|| BlackJackError::ValueError(format!("No column named '{}'", name)))?;
+            let name = name.as_str();
+            let ids = match meta.dtype {
+                DType::F64 => self.get_column::<f64>(name).unwrap().group_ids(),
+                DType::I64 => self.get_column::<i64>(name).unwrap().group_ids(),
+                DType::F32 => self.get_column::<f32>(name).unwrap().group_ids(),
+                DType::I32 => self.get_column::<i32>(name).unwrap().group_ids(),
+                DType::I16 => self.get_column::<i16>(name).unwrap().group_ids(),
+                DType::I8 => self.get_column::<i8>(name).unwrap().group_ids(),
+                DType::U64 => self.get_column::<u64>(name).unwrap().group_ids(),
+                DType::U32 => self.get_column::<u32>(name).unwrap().group_ids(),
+                DType::U16 => self.get_column::<u16>(name).unwrap().group_ids(),
+                DType::U8 => self.get_column::<u8>(name).unwrap().group_ids(),
+                DType::STRING => self.get_column::<String>(name).unwrap().group_ids(),
+                DType::BOOL => self.get_column::<bool>(name).unwrap().group_ids(),
+                DType::DATETIME => self.get_column::<DateTime>(name).unwrap().group_ids(),
+                DType::DURATION => self.get_column::<Duration>(name).unwrap().group_ids(),
+                DType::CATEGORICAL => {
+                    let categorical: &Categorical = self.data.get(name).unwrap();
+                    categorical.codes().group_ids()
+                }
+            };
+            for (key, id) in keys.iter_mut().zip(ids) {
+                key.push(id);
+            }
+        }
+
+        let duplicated = duplicated_keys(&keys, keep);
+        self.filter_by_mask(&Series::from_vec(
+            duplicated.into_iter().map(|duplicate| !duplicate).collect(),
+        ))
+    }
+
     /// Drop positions within the `Series`
     ///
     /// ## Example
@@ -126,16 +236,917 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
                     let s: &mut Series<i32> = &mut self.get_column_mut(meta.name.as_str()).unwrap();
                     s.drop_positions(positions.clone())
                 }
+                DType::I16 => {
+                    let s: &mut Series<i16> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::I8 => {
+                    let s: &mut Series<i8> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::U64 => {
+                    let s: &mut Series<u64> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::U32 => {
+                    let s: &mut Series<u32> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::U16 => {
+                    let s: &mut Series<u16> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::U8 => {
+                    let s: &mut Series<u8> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
                 DType::STRING => {
                     let s: &mut Series<String> =
                         &mut self.get_column_mut(meta.name.as_str()).unwrap();
                     s.drop_positions(positions.clone())
                 }
+                DType::BOOL => {
+                    let s: &mut Series<bool> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::DATETIME => {
+                    let s: &mut Series<DateTime> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::DURATION => {
+                    let s: &mut Series<Duration> = self.get_column_mut(meta.name.as_str()).unwrap();
+                    s.drop_positions(positions.clone())
+                }
+                DType::CATEGORICAL => {
+                    let c: &mut Categorical = self.data.get_mut(&meta.name).unwrap();
+                    c.drop_positions(positions.clone())
+                }
             };
         }
         self.index.drop_positions(positions);
     }
 
+    /// Sort the rows of the dataframe in place by the values of one or more columns,
+    /// each in ascending or descending order. Later columns break ties of earlier ones,
+    /// rows which are still tied keep their order, and missing values are placed last.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut group = Series::from_vec(vec!["b", "a", "b", "a"].iter().map(|s| s.to_string()).collect());
+    /// group.set_name("group");
+    ///
+    /// let mut score = Series::from_vec(vec![1.5, 2.5, 3.5, 0.5]);
+    /// score.set_name("score");
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(group).is_ok());
+    /// assert!(df.add_column(score).is_ok());
+    ///
+    /// df.sort_values(&["group", "score"], &[true, false]).unwrap();
+    ///
+    /// let score: &Series<f64> = df.get_column("score").unwrap();
+    /// assert_eq!(score.values, vec![2.5, 0.5, 3.5, 1.5]);
+    /// ```
+    pub fn sort_values(&mut self, by: &[&str], ascending: &[bool]) -> Result<(), BlackJackError> {
+        let positions = self.sort_positions(by, ascending)?;
+        for meta in self.meta.clone() {
+            match meta.dtype {
+                DType::F64 => self.take_column::<f64>(&meta.name, &positions),
+                DType::I64 => self.take_column::<i64>(&meta.name, &positions),
+                DType::F32 => self.take_column::<f32>(&meta.name, &positions),
+                DType::I32 => self.take_column::<i32>(&meta.name, &positions),
+                DType::I16 => self.take_column::<i16>(&meta.name, &positions),
+                DType::I8 => self.take_column::<i8>(&meta.name, &positions),
+                DType::U64 => self.take_column::<u64>(&meta.name, &positions),
+                DType::U32 => self.take_column::<u32>(&meta.name, &positions),
+                DType::U16 => self.take_column::<u16>(&meta.name, &positions),
+                DType::U8 => self.take_column::<u8>(&meta.name, &positions),
+                DType::STRING => self.take_column::<String>(&meta.name, &positions),
+                DType::BOOL => self.take_column::<bool>(&meta.name, &positions),
+                DType::DATETIME => self.take_column::<DateTime>(&meta.name, &positions),
+                DType::DURATION => self.take_column::<Duration>(&meta.name, &positions),
+                DType::CATEGORICAL => {
+                    let c: &mut Categorical = self.data.get_mut(&meta.name).unwrap();
+                    *c = c.take(&positions)
+                }
+            };
+        }
+        self.index = self.index.take(&positions);
+        Ok(())
+    }
+
+    // The positions of the rows in the order sorted by the given columns
+    fn sort_positions(
+        &self,
+        by: &[&str],
+        ascending: &[bool],
+    ) -> Result<Vec<usize>, BlackJackError> {
+        if by.len() != ascending.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Sorting by {} columns, but given {} sort orders",
+                by.len(),
+                ascending.len()
+            )));
+        }
+
+        let mut comparators = vec![];
+        for (name, ascending) in by.iter().zip(ascending) {
+            let meta = self
+                .meta
+                .iter()
+                .find(|meta| meta.name == *name)
+                .ok_or_else(|| BlackJackError::ValueError(format!("No column named '{}'", name)))?;
+            let comparator = match meta.dtype {
+                DType::F64 => self.key_comparator::<f64>(name, *ascending),
+                DType::I64 => self.key_comparator::<i64>(name, *ascending),
+                DType::F32 => self.key_comparator::<f32>(name, *ascending),
+                DType::I32 => self.key_comparator::<i32>(name, *ascending),
+                DType::I16 => self.key_comparator::<i16>(name, *ascending),
+                DType::I8 => self.key_comparator::<i8>(name, *ascending),
+                DType::U64 => self.key_comparator::<u64>(name, *ascending),
+                DType::U32 => self.key_comparator::<u32>(name, *ascending),
+                DType::U16 => self.key_comparator::<u16>(name, *ascending),
+                DType::U8 => self.key_comparator::<u8>(name, *ascending),
+                DType::STRING => self.key_comparator::<String>(name, *ascending),
+                DType::BOOL => self.key_comparator::<bool>(name, *ascending),
+                DType::DATETIME => self.key_comparator::<DateTime>(name, *ascending),
+                DType::DURATION => self.key_comparator::<Duration>(name, *ascending),
+                DType::CATEGORICAL => {
+                    // Compare the rank of each code's category, rather than the strings
+                    let categorical: &Categorical = self.data.get(&meta.name).unwrap();
+                    let categories = categorical.categories();
+                    let mut order = (0..categories.len()).collect::<Vec<usize>>();
+                    order.sort_by(|a, b| categories[*a].cmp(&categories[*b]));
+                    let mut ranks = vec![0; categories.len()];
+                    for (rank, code) in order.into_iter().enumerate() {
+                        ranks[code] = rank;
+                    }
+                    by_key(
+                        move |idx| {
+                            categorical
+                                .codes()
+                                .get(idx)
+                                .map(|code| ranks[*code as usize])
+                        },
+                        *ascending,
+                    )
+                }
+            };
+            comparators.push(comparator);
+        }
+
+        let mut positions = (0..self.len()).collect::<Vec<usize>>();
+        positions.sort_by(|a, b| {
+            comparators
+                .iter()
+                .map(|compare| compare(*a, *b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        Ok(positions)
+    }
+
+    // Compare rows by the values of a column
+    fn key_comparator<T>(
+        &self,
+        name: &str,
+        ascending: bool,
+    ) -> Box<dyn Fn(usize, usize) -> Ordering + '_>
+    where
+        T: BlackJackData + PartialOrd + 'static,
+    {
+        let series: &Series<T> = self.data.get(name).unwrap();
+        let na = series.isna().collect::<Vec<bool>>();
+        by_key(
+            move |idx| {
+                if na[idx] {
+                    None
+                } else {
+                    Some(&series.values[idx])
+                }
+            },
+            ascending,
+        )
+    }
+
+    // Reorder a column to the given positions
+    fn take_column<T>(&mut self, name: &str, positions: &[usize])
+    where
+        T: BlackJackData + 'static,
+    {
+        let series: &mut Series<T> = self.data.get_mut(name).unwrap();
+        *series = series.take(positions);
+    }
+
+    /// The `k` rows with the largest values of a column, in descending order of it;
+    /// see [`Series::nlargest`]. Yields a new dataframe of those rows, keeping their
+    /// index, or a `ValueError` if there's no such column.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![5, 1, 8, 3])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec!["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect())).is_ok());
+    ///
+    /// let top = df.nlargest(2, "col_0").unwrap();
+    /// assert_eq!(top.index().values, vec![2, 0]);
+    ///
+    /// let letters: &Series<String> = top.get_column("col_1").unwrap();
+    /// assert_eq!(letters.values, vec!["c", "a"]);
+    /// ```
+    pub fn nlargest(&self, k: usize, column: &str) -> Result<DataFrame<I>, BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let positions = self.select_positions(column, k, false)?;
+        Ok(self.take_rows(&positions))
+    }
+
+    /// The `k` rows with the smallest values of a column, in ascending order of it;
+    /// see [`Series::nsmallest`]. Yields a new dataframe of those rows, keeping their
+    /// index, or a `ValueError` if there's no such column.
+    pub fn nsmallest(&self, k: usize, column: &str) -> Result<DataFrame<I>, BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let positions = self.select_positions(column, k, true)?;
+        Ok(self.take_rows(&positions))
+    }
+
+    // Positions of the first `k` rows in order of a column, see `Series::select_positions`
+    fn select_positions(
+        &self,
+        name: &str,
+        k: usize,
+        ascending: bool,
+    ) -> Result<Vec<usize>, BlackJackError> {
+        let meta = self
+            .meta
+            .iter()
+            .find(|meta| meta.name == name)
+            .ok_or_else(|| BlackJackError::ValueError(format!("No column named '{}'", name)))?;
+        let positions = match meta.dtype {
+            DType::F64 => self
+                .typed_column::<f64>(name)?
+                .select_positions(k, ascending),
+            DType::I64 => self
+                .typed_column::<i64>(name)?
+                .select_positions(k, ascending),
+            DType::F32 => self
+                .typed_column::<f32>(name)?
+                .select_positions(k, ascending),
+            DType::I32 => self
+                .typed_column::<i32>(name)?
+                .select_positions(k, ascending),
+            DType::I16 => self
+                .typed_column::<i16>(name)?
+                .select_positions(k, ascending),
+            DType::I8 => self
+                .typed_column::<i8>(name)?
+                .select_positions(k, ascending),
+            DType::U64 => self
+                .typed_column::<u64>(name)?
+                .select_positions(k, ascending),
+            DType::U32 => self
+                .typed_column::<u32>(name)?
+                .select_positions(k, ascending),
+            DType::U16 => self
+                .typed_column::<u16>(name)?
+                .select_positions(k, ascending),
+            DType::U8 => self
+                .typed_column::<u8>(name)?
+                .select_positions(k, ascending),
+            DType::STRING => self
+                .typed_column::<String>(name)?
+                .select_positions(k, ascending),
+            DType::BOOL => self
+                .typed_column::<bool>(name)?
+                .select_positions(k, ascending),
+            DType::DATETIME => self
+                .typed_column::<DateTime>(name)?
+                .select_positions(k, ascending),
+            DType::DURATION => self
+                .typed_column::<Duration>(name)?
+                .select_positions(k, ascending),
+            DType::CATEGORICAL => {
+                let categorical: &Categorical = self.data.get(name).unwrap();
+                categorical.to_series().select_positions(k, ascending)
+            }
+        };
+        Ok(positions)
+    }
+
+    // New dataframe of the rows at `positions`, in that order, with their index
+    fn take_rows(&self, positions: &[usize]) -> DataFrame<I>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut df = DataFrame::new();
+        for meta in &self.meta {
+            let name = meta.name.as_str();
+            match meta.dtype {
+                DType::F64 => df.add_column(self.get_column::<f64>(name).unwrap().take(positions)),
+                DType::I64 => df.add_column(self.get_column::<i64>(name).unwrap().take(positions)),
+                DType::F32 => df.add_column(self.get_column::<f32>(name).unwrap().take(positions)),
+                DType::I32 => df.add_column(self.get_column::<i32>(name).unwrap().take(positions)),
+                DType::I16 => df.add_column(self.get_column::<i16>(name).unwrap().take(positions)),
+                DType::I8 => df.add_column(self.get_column::<i8>(name).unwrap().take(positions)),
+                DType::U64 => df.add_column(self.get_column::<u64>(name).unwrap().take(positions)),
+                DType::U32 => df.add_column(self.get_column::<u32>(name).unwrap().take(positions)),
+                DType::U16 => df.add_column(self.get_column::<u16>(name).unwrap().take(positions)),
+                DType::U8 => df.add_column(self.get_column::<u8>(name).unwrap().take(positions)),
+                DType::STRING => {
+                    df.add_column(self.get_column::<String>(name).unwrap().take(positions))
+                }
+                DType::BOOL => {
+                    df.add_column(self.get_column::<bool>(name).unwrap().take(positions))
+                }
+                DType::DATETIME => {
+                    df.add_column(self.get_column::<DateTime>(name).unwrap().take(positions))
+                }
+                DType::DURATION => {
+                    df.add_column(self.get_column::<Duration>(name).unwrap().take(positions))
+                }
+                DType::CATEGORICAL => {
+                    let categorical: &Categorical = self.data.get(name).unwrap();
+                    df.add_categorical_column(categorical.take(positions))
+                }
+            }
+            .unwrap();
+        }
+        df.index = self.index.take(positions);
+        df
+    }
+
+    /// Shift the rows of every column by a number of periods, see [`Series::shift`],
+    /// yielding a new dataframe with the same index.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()])).is_ok());
+    ///
+    /// let shifted = df.shift(1);
+    /// let col: &Series<String> = shifted.get_column("col_1").unwrap();
+    /// assert_eq!(col.get(0), None);
+    /// assert_eq!(col.get(1), Some(&"a".to_string()));
+    /// ```
+    pub fn shift(&self, periods: i64) -> DataFrame<I>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut df = DataFrame::new();
+        for meta in &self.meta {
+            let name = meta.name.as_str();
+            match meta.dtype {
+                DType::F64 => df.add_column(self.get_column::<f64>(name).unwrap().shift(periods)),
+                DType::I64 => df.add_column(self.get_column::<i64>(name).unwrap().shift(periods)),
+                DType::F32 => df.add_column(self.get_column::<f32>(name).unwrap().shift(periods)),
+                DType::I32 => df.add_column(self.get_column::<i32>(name).unwrap().shift(periods)),
+                DType::I16 => df.add_column(self.get_column::<i16>(name).unwrap().shift(periods)),
+                DType::I8 => df.add_column(self.get_column::<i8>(name).unwrap().shift(periods)),
+                DType::U64 => df.add_column(self.get_column::<u64>(name).unwrap().shift(periods)),
+                DType::U32 => df.add_column(self.get_column::<u32>(name).unwrap().shift(periods)),
+                DType::U16 => df.add_column(self.get_column::<u16>(name).unwrap().shift(periods)),
+                DType::U8 => df.add_column(self.get_column::<u8>(name).unwrap().shift(periods)),
+                DType::STRING => {
+                    df.add_column(self.get_column::<String>(name).unwrap().shift(periods))
+                }
+                DType::BOOL => df.add_column(self.get_column::<bool>(name).unwrap().shift(periods)),
+                DType::DATETIME => {
+                    df.add_column(self.get_column::<DateTime>(name).unwrap().shift(periods))
+                }
+                DType::DURATION => {
+                    df.add_column(self.get_column::<Duration>(name).unwrap().shift(periods))
+                }
+                DType::CATEGORICAL => {
+                    let categorical: &Categorical = self.data.get(name).unwrap();
+                    df.add_categorical_column(categorical.shift(periods))
+                }
+            }
+            .unwrap();
+        }
+        df.index = self.index.clone();
+        df
+    }
+
+    /// Difference of each row with the row `periods` before it, see [`Series::diff`],
+    /// for every numeric column; yielding a new dataframe of `f64` columns with the same index.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 4, 9])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()])).is_ok());
+    ///
+    /// let diff = df.diff(1);
+    /// assert_eq!(diff.n_columns(), 1);
+    ///
+    /// let col: &Series<f64> = diff.get_column("col_0").unwrap();
+    /// assert_eq!(col.values[1..], [3., 5.]);
+    /// ```
+    pub fn diff(&self, periods: i64) -> DataFrame<I>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        self.map_numeric_columns(|series| series.diff(periods))
+    }
+
+    /// Fractional change of each row from the row `periods` before it, see
+    /// [`Series::pct_change`], for every numeric column; yielding a new dataframe of
+    /// `f64` columns with the same index.
+    pub fn pct_change(&self, periods: i64) -> DataFrame<I>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        self.map_numeric_columns(|series| series.pct_change(periods))
+    }
+
+    // New dataframe with the same index, from applying `func` to each numeric column as `f64`
+    fn map_numeric_columns<F>(&self, func: F) -> DataFrame<I>
+    where
+        F: Fn(&Series<f64>) -> Series<f64>,
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut df = DataFrame::new();
+        for column in self.numeric_columns() {
+            df.add_column(func(&column)).unwrap();
+        }
+        df.index = self.index.clone();
+        df
+    }
+
+    // Every numeric column converted to `f64`, in order
+    fn numeric_columns(&self) -> Vec<Series<f64>> {
+        self.meta
+            .iter()
+            .filter_map(|meta| {
+                let name = meta.name.as_str();
+                match meta.dtype {
+                    DType::F64 => self.get_column::<f64>(name).map(as_f64),
+                    DType::I64 => self.get_column::<i64>(name).map(as_f64),
+                    DType::F32 => self.get_column::<f32>(name).map(as_f64),
+                    DType::I32 => self.get_column::<i32>(name).map(as_f64),
+                    DType::I16 => self.get_column::<i16>(name).map(as_f64),
+                    DType::I8 => self.get_column::<i8>(name).map(as_f64),
+                    DType::U64 => self.get_column::<u64>(name).map(as_f64),
+                    DType::U32 => self.get_column::<u32>(name).map(as_f64),
+                    DType::U16 => self.get_column::<u16>(name).map(as_f64),
+                    DType::U8 => self.get_column::<u8>(name).map(as_f64),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Sample covariance between every pair of numeric columns, see [`Series::cov`];
+    /// yielding a square dataframe with a column per numeric column, indexed by their
+    /// names. Missing values are excluded pair by pair, and the covariance is null
+    /// where fewer than two pairs remain.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec![2., 4., 6.])).is_ok());
+    ///
+    /// let cov = df.cov();
+    /// assert_eq!(cov.index().values, vec!["col_0", "col_1"]);
+    ///
+    /// let col: &Series<f64> = cov.get_column("col_1").unwrap();
+    /// assert_eq!(col.values, vec![2., 4.]);
+    /// ```
+    pub fn cov(&self) -> DataFrame<String> {
+        self.pairwise_matrix(|x, y| x.cov(y))
+    }
+
+    /// Correlation by `method` between every pair of numeric columns, see
+    /// [`Series::corr`]; yielding a square dataframe with a column per numeric column,
+    /// indexed by their names. Missing values are excluded pair by pair, and the
+    /// correlation is null where fewer than two pairs remain.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3, 4])).is_ok());
+    /// assert!(df.add_column(Series::from_options(vec![Some(8.), None, Some(2.), Some(1.)])).is_ok());
+    ///
+    /// let corr = df.corr(CorrelationMethod::Spearman);
+    /// let col: &Series<f64> = corr.get_column("col_0").unwrap();
+    /// assert_eq!(col.values, vec![1., -1.]);
+    /// ```
+    pub fn corr(&self, method: CorrelationMethod) -> DataFrame<String> {
+        self.pairwise_matrix(|x, y| x.corr(y, method))
+    }
+
+    // Square dataframe of `func` between each pair of numeric columns, null where it fails
+    fn pairwise_matrix<F>(&self, func: F) -> DataFrame<String>
+    where
+        F: Fn(&Series<f64>, &Series<f64>) -> Result<f64, BlackJackError>,
+    {
+        let columns = self.numeric_columns();
+        let mut matrix = vec![vec![None; columns.len()]; columns.len()];
+        for i in 0..columns.len() {
+            for j in i..columns.len() {
+                let value = func(&columns[i], &columns[j]).ok();
+                matrix[i][j] = value;
+                matrix[j][i] = value;
+            }
+        }
+
+        let names = columns
+            .iter()
+            .map(|column| column.name().unwrap())
+            .collect::<Vec<String>>();
+        let mut df = DataFrame {
+            index: Series::from_vec(names.clone()),
+            meta: vec![],
+            data: Baggie::new(),
+        };
+        for (name, values) in names.iter().zip(matrix) {
+            let mut series = Series::from_options(values);
+            series.set_name(name);
+            df.insert_column(series);
+        }
+        df
+    }
+
+    /// Fill missing values of the named columns with the value given for each; see
+    /// [`Series::fillna`]. Categorical columns are filled with a string, which becomes
+    /// a new category if needed.
+    ///
+    /// Returns a `ValueError`, without filling any column, if a column doesn't exist
+    /// or the value doesn't match its dtype.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_options(vec![Some(1.), None])).is_ok());
+    /// assert!(df.add_column(Series::from_options(vec![None, Some("b".to_string())])).is_ok());
+    ///
+    /// let fill = "a".to_string();
+    /// let mut values = HashMap::new();
+    /// values.insert("col_0", Datum::F64(&0.));
+    /// values.insert("col_1", Datum::STR(&fill));
+    /// assert!(df.fillna(&values).is_ok());
+    ///
+    /// let col: &Series<f64> = df.get_column("col_0").unwrap();
+    /// assert_eq!(col.values, vec![1., 0.]);
+    ///
+    /// values.insert("col_0", Datum::I32(&0));
+    /// assert!(df.fillna(&values).is_err());
+    /// ```
+    pub fn fillna(&mut self, values: &HashMap<&str, Datum>) -> Result<(), BlackJackError> {
+        for (name, value) in values {
+            let meta = self
+                .meta
+                .iter()
+                .find(|meta| meta.name == *name)
+                .ok_or_else(|| BlackJackError::ValueError(format!("No column named {}", name)))?;
+            let compatible = matches!(
+                (&meta.dtype, value),
+                (DType::F64, Datum::F64(_))
+                    | (DType::I64, Datum::I64(_))
+                    | (DType::F32, Datum::F32(_))
+                    | (DType::I32, Datum::I32(_))
+                    | (DType::I16, Datum::I16(_))
+                    | (DType::I8, Datum::I8(_))
+                    | (DType::U64, Datum::U64(_))
+                    | (DType::U32, Datum::U32(_))
+                    | (DType::U16, Datum::U16(_))
+                    | (DType::U8, Datum::U8(_))
+                    | (DType::STRING, Datum::STR(_))
+                    | (DType::BOOL, Datum::BOOL(_))
+                    | (DType::DATETIME, Datum::DATETIME(_))
+                    | (DType::DURATION, Datum::DURATION(_))
+                    | (DType::CATEGORICAL, Datum::STR(_))
+            );
+            if !compatible {
+                return Err(BlackJackError::ValueError(format!(
+                    "Fill value for column {} doesn't match its dtype {:?}",
+                    name, meta.dtype
+                )));
+            }
+        }
+
+        for (name, value) in values {
+            let dtype = self
+                .meta
+                .iter()
+                .find(|meta| meta.name == *name)
+                .unwrap()
+                .dtype
+                .clone();
+            match (dtype, value) {
+                (DType::F64, Datum::F64(value)) => self.fill_column::<f64>(name, value),
+                (DType::I64, Datum::I64(value)) => self.fill_column::<i64>(name, value),
+                (DType::F32, Datum::F32(value)) => self.fill_column::<f32>(name, value),
+                (DType::I32, Datum::I32(value)) => self.fill_column::<i32>(name, value),
+                (DType::I16, Datum::I16(value)) => self.fill_column::<i16>(name, value),
+                (DType::I8, Datum::I8(value)) => self.fill_column::<i8>(name, value),
+                (DType::U64, Datum::U64(value)) => self.fill_column::<u64>(name, value),
+                (DType::U32, Datum::U32(value)) => self.fill_column::<u32>(name, value),
+                (DType::U16, Datum::U16(value)) => self.fill_column::<u16>(name, value),
+                (DType::U8, Datum::U8(value)) => self.fill_column::<u8>(name, value),
+                (DType::STRING, Datum::STR(value)) => self.fill_column::<String>(name, value),
+                (DType::BOOL, Datum::BOOL(value)) => self.fill_column::<bool>(name, value),
+                (DType::DATETIME, Datum::DATETIME(value)) => {
+                    self.fill_column::<DateTime>(name, value)
+                }
+                (DType::DURATION, Datum::DURATION(value)) => {
+                    self.fill_column::<Duration>(name, value)
+                }
+                (DType::CATEGORICAL, Datum::STR(value)) => {
+                    let categorical: &mut Categorical = self.data.get_mut(*name).unwrap();
+                    *categorical = categorical.fillna(value);
+                }
+                _ => unreachable!(),
+            }
+        }
+        Ok(())
+    }
+
+    // Fill missing values of a column with `value`
+    fn fill_column<T>(&mut self, name: &str, value: &T)
+    where
+        T: BlackJackData + 'static,
+    {
+        let series: &mut Series<T> = self.data.get_mut(name).unwrap();
+        *series = series.fillna(value.clone());
+    }
+
+    /// Summary statistics of every numeric, string and categorical column, see
+    /// [`Series::describe`]; yielding a dataframe with a column per summarised column,
+    /// indexed by the name of each statistic. Statistics which don't apply to a
+    /// column's dtype are null, and columns of other dtypes are skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec!["a".to_string(), "b".to_string(), "a".to_string()])).is_ok());
+    ///
+    /// let summary = df.describe();
+    /// assert_eq!(summary.index().values[..5], ["count", "unique", "top", "freq", "mean"]);
+    ///
+    /// let numbers: &Series<f64> = summary.get_column("col_0").unwrap();
+    /// assert_eq!(numbers.get(0), Some(&3.));
+    /// assert_eq!(numbers.get(4), Some(&2.));
+    ///
+    /// let letters: &Series<String> = summary.get_column("col_1").unwrap();
+    /// assert_eq!(letters.get(2), Some(&"a".to_string()));
+    /// assert_eq!(letters.get(4), None);
+    /// ```
+    pub fn describe(&self) -> DataFrame<String> {
+        let mut summaries = vec![];
+        for meta in &self.meta {
+            let name = meta.name.as_str();
+            let summary = match meta.dtype {
+                DType::F64 => self.get_column::<f64>(name).map(|s| s.describe()),
+                DType::I64 => self.get_column::<i64>(name).map(|s| s.describe()),
+                DType::F32 => self.get_column::<f32>(name).map(|s| s.describe()),
+                DType::I32 => self.get_column::<i32>(name).map(|s| s.describe()),
+                DType::I16 => self.get_column::<i16>(name).map(|s| s.describe()),
+                DType::I8 => self.get_column::<i8>(name).map(|s| s.describe()),
+                DType::U64 => self.get_column::<u64>(name).map(|s| s.describe()),
+                DType::U32 => self.get_column::<u32>(name).map(|s| s.describe()),
+                DType::U16 => self.get_column::<u16>(name).map(|s| s.describe()),
+                DType::U8 => self.get_column::<u8>(name).map(|s| s.describe()),
+                _ => None,
+            };
+            let summary = summary
+                .map(|(stats, values)| (stats, GenericSeriesContainer::F64(values)))
+                .or_else(|| {
+                    let series = match meta.dtype {
+                        DType::STRING => self.get_column::<String>(name)?.clone(),
+                        DType::CATEGORICAL => self.get_categorical_column(name)?.to_series(),
+                        _ => return None,
+                    };
+                    let (stats, values) = series.describe();
+                    Some((stats, GenericSeriesContainer::STRING(values)))
+                });
+            summaries.extend(summary);
+        }
+
+        // Statistics of any column, in a consistent order
+        let index = DESCRIBE_STATS
+            .iter()
+            .filter(|stat| {
+                summaries
+                    .iter()
+                    .any(|(stats, _)| stats.values.iter().any(|s| s == *stat))
+            })
+            .map(|stat| stat.to_string())
+            .collect::<Vec<String>>();
+
+        let mut df = DataFrame {
+            index: Series::from_vec(index),
+            meta: vec![],
+            data: Baggie::new(),
+        };
+        for (stats, values) in summaries {
+            match values {
+                GenericSeriesContainer::F64(values) => {
+                    df.insert_column(reindex_stats(&df.index, &stats, &values))
+                }
+                GenericSeriesContainer::STRING(values) => {
+                    df.insert_column(reindex_stats(&df.index, &stats, &values))
+                }
+                _ => unreachable!(),
+            }
+        }
+        df
+    }
+
+    /// Mask of the rows where the named column is any of `values`, see [`Series::isin`];
+    /// for use with [`DataFrame::filter_by_mask`].
+    ///
+    /// Returns a `ValueError` if there's no column of dtype `T` by that name.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3, 4])).is_ok());
+    ///
+    /// let mask = df.isin("col_0", &[1, 4]).unwrap();
+    /// df.filter_by_mask(&mask).unwrap();
+    /// assert_eq!(df.len(), 2);
+    ///
+    /// assert!(df.isin("col_0", &[1.]).is_err());
+    /// ```
+    pub fn isin<T>(&self, column: &str, values: &[T]) -> Result<Series<bool>, BlackJackError>
+    where
+        T: BlackJackData + 'static,
+    {
+        Ok(self.typed_column::<T>(column)?.isin(values))
+    }
+
+    /// Mask of the rows where the named column is between `low` and `high`, see
+    /// [`Series::between`]; for use with [`DataFrame::filter_by_mask`].
+    ///
+    /// Returns a `ValueError` if there's no column of dtype `T` by that name.
+    pub fn between<T>(
+        &self,
+        column: &str,
+        low: T,
+        high: T,
+        inclusive: Inclusive,
+    ) -> Result<Series<bool>, BlackJackError>
+    where
+        T: BlackJackData + PartialOrd + 'static,
+    {
+        Ok(self
+            .typed_column::<T>(column)?
+            .between(low, high, inclusive))
+    }
+
+    /// Keep the rows where `mask` is `true`, with every column null in the others;
+    /// yielding a new dataframe with the same index. See [`Series::where_`].
+    ///
+    /// Returns a `LengthMismatch` error if the mask differs in length.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3])).is_ok());
+    ///
+    /// let mask = df.between("col_0", 2, 3, Inclusive::Both).unwrap();
+    /// let kept = df.where_(&mask).unwrap();
+    /// let col: &Series<i32> = kept.get_column("col_0").unwrap();
+    /// assert_eq!(col.get(0), None);
+    /// assert_eq!(col.get(1), Some(&2));
+    ///
+    /// let masked = df.mask(&mask).unwrap();
+    /// let col: &Series<i32> = masked.get_column("col_0").unwrap();
+    /// assert_eq!(col.null_count(), 2);
+    /// ```
+    pub fn where_(&self, mask: &Series<bool>) -> Result<DataFrame<I>, BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        self.null_rows_by_mask(mask, false)
+    }
+
+    /// Null every column in the rows where `mask` is `true`, keeping the others;
+    /// yielding a new dataframe with the same index. See [`Series::mask`].
+    ///
+    /// Returns a `LengthMismatch` error if the mask differs in length.
+    pub fn mask(&self, mask: &Series<bool>) -> Result<DataFrame<I>, BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        self.null_rows_by_mask(mask, true)
+    }
+
+    // The named column as a series of dtype `T`, or a `ValueError`
+    fn typed_column<T>(&self, name: &str) -> Result<&Series<T>, BlackJackError>
+    where
+        T: BlackJackData + 'static,
+    {
+        self.get_column::<T>(name).ok_or_else(|| {
+            BlackJackError::ValueError(format!("No column named {} of the requested dtype", name))
+        })
+    }
+
+    // New dataframe with every column null in the rows where the mask, counting null
+    // as `false`, is `null_if`
+    fn null_rows_by_mask(
+        &self,
+        mask: &Series<bool>,
+        null_if: bool,
+    ) -> Result<DataFrame<I>, BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        if mask.len() != self.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has length: {}, cannot select by mask of length: {}",
+                self.len(),
+                mask.len()
+            )));
+        }
+        let rows = Series::from_vec(
+            (0..mask.len())
+                .map(|idx| (!mask.is_null(idx) && mask.values[idx]) == null_if)
+                .collect(),
+        );
+
+        let mut df = DataFrame::new();
+        for meta in &self.meta {
+            let name = meta.name.as_str();
+            match meta.dtype {
+                DType::F64 => {
+                    df.add_column(null_rows(self.get_column::<f64>(name).unwrap(), &rows))
+                }
+                DType::I64 => {
+                    df.add_column(null_rows(self.get_column::<i64>(name).unwrap(), &rows))
+                }
+                DType::F32 => {
+                    df.add_column(null_rows(self.get_column::<f32>(name).unwrap(), &rows))
+                }
+                DType::I32 => {
+                    df.add_column(null_rows(self.get_column::<i32>(name).unwrap(), &rows))
+                }
+                DType::I16 => {
+                    df.add_column(null_rows(self.get_column::<i16>(name).unwrap(), &rows))
+                }
+                DType::I8 => df.add_column(null_rows(self.get_column::<i8>(name).unwrap(), &rows)),
+                DType::U64 => {
+                    df.add_column(null_rows(self.get_column::<u64>(name).unwrap(), &rows))
+                }
+                DType::U32 => {
+                    df.add_column(null_rows(self.get_column::<u32>(name).unwrap(), &rows))
+                }
+                DType::U16 => {
+                    df.add_column(null_rows(self.get_column::<u16>(name).unwrap(), &rows))
+                }
+                DType::U8 => df.add_column(null_rows(self.get_column::<u8>(name).unwrap(), &rows)),
+                DType::STRING => {
+                    df.add_column(null_rows(self.get_column::<String>(name).unwrap(), &rows))
+                }
+                DType::BOOL => {
+                    df.add_column(null_rows(self.get_column::<bool>(name).unwrap(), &rows))
+                }
+                DType::DATETIME => {
+                    df.add_column(null_rows(self.get_column::<DateTime>(name).unwrap(), &rows))
+                }
+                DType::DURATION => {
+                    df.add_column(null_rows(self.get_column::<Duration>(name).unwrap(), &rows))
+                }
+                DType::CATEGORICAL => {
+                    let categorical: &Categorical = self.data.get(name).unwrap();
+                    let codes = null_rows(categorical.codes(), &rows);
+                    let categories = categorical.categories().to_vec();
+                    df.add_categorical_column(Categorical::from_codes(codes, categories)?)
+                }
+            }?;
+        }
+        df.index = self.index.clone();
+        Ok(df)
+    }
+
     /// Iterator over rows of a dataframe where each element contained is a reference
     ///
     /// ## Example
@@ -172,10 +1183,59 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
                         let series: &Series<i32> = self.data.get(&meta.name).unwrap();
                         row.add(Element::new(meta.name.clone(), Datum::I32(&series[idx])))
                     }
+                    DType::I16 => {
+                        let series: &Series<i16> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::I16(&series[idx])))
+                    }
+                    DType::I8 => {
+                        let series: &Series<i8> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::I8(&series[idx])))
+                    }
+                    DType::U64 => {
+                        let series: &Series<u64> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::U64(&series[idx])))
+                    }
+                    DType::U32 => {
+                        let series: &Series<u32> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::U32(&series[idx])))
+                    }
+                    DType::U16 => {
+                        let series: &Series<u16> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::U16(&series[idx])))
+                    }
+                    DType::U8 => {
+                        let series: &Series<u8> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::U8(&series[idx])))
+                    }
                     DType::STRING => {
                         let series: &Series<String> = self.data.get(&meta.name).unwrap();
                         row.add(Element::new(meta.name.clone(), Datum::STR(&series[idx])))
                     }
+                    DType::BOOL => {
+                        let series: &Series<bool> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(meta.name.clone(), Datum::BOOL(&series[idx])))
+                    }
+                    DType::DATETIME => {
+                        let series: &Series<DateTime> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(
+                            meta.name.clone(),
+                            Datum::DATETIME(&series[idx]),
+                        ))
+                    }
+                    DType::DURATION => {
+                        let series: &Series<Duration> = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(
+                            meta.name.clone(),
+                            Datum::DURATION(&series[idx]),
+                        ))
+                    }
+                    DType::CATEGORICAL => {
+                        let categorical: &Categorical = self.data.get(&meta.name).unwrap();
+                        row.add(Element::new(
+                            meta.name.clone(),
+                            Datum::STR(categorical.category_or_default(idx)),
+                        ))
+                    }
                 }
             }
             row
@@ -237,6 +1297,38 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         self.index.len()
     }
 
+    /// The index of the dataframe, labelling each row; positions `0..len` unless set
+    /// with [`DataFrame::set_index`].
+    pub fn index(&self) -> &Series<I> {
+        &self.index
+    }
+
+    /// Label the rows of the dataframe with `index`, which must have a label per row
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2])).is_ok());
+    ///
+    /// assert!(df.set_index(Series::from_vec(vec![10, 20])).is_ok());
+    /// assert_eq!(df.index().values, vec![10, 20]);
+    ///
+    /// assert!(df.set_index(Series::from_vec(vec![10])).is_err());
+    /// ```
+    pub fn set_index(&mut self, index: Series<I>) -> Result<(), BlackJackError> {
+        if self.n_columns() > 0 && index.len() != self.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has length: {}, cannot set index of length: {}",
+                self.len(),
+                index.len()
+            )));
+        }
+        self.index = index;
+        Ok(())
+    }
+
     /// Quickly identify if the dataframe is empty.
     pub fn is_empty(&self) -> bool {
         !self.len() > 0
@@ -251,17 +1343,7 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         Vec<I>: std::iter::FromIterator<i32>,
     {
         let mut series = series;
-
-        // Ensure length is a match if we have columns
-        if self.len() > 0 && self.len() != series.len() {
-            return Err(BlackJackError::LengthMismatch(format!(
-                "DataFrame has length: {}, cannot add series of length: {}",
-                self.len(),
-                series.len()
-            )));
-        } else {
-            self.index = Series::from_vec((0..series.len() as i32).collect::<Vec<I>>())
-        }
+        self.reset_index(series.len())?;
 
         if let None = series.name() {
             series.set_name(&format!("col_{}", self.n_columns()))
@@ -274,6 +1356,63 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         Ok(())
     }
 
+    /// Add a [`Categorical`] column to this dataframe.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec!["a".to_string(), "b".to_string()]);
+    /// series.set_name("letters");
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_categorical_column(series.to_categorical()).unwrap();
+    ///
+    /// let letters = df.get_categorical_column("letters").unwrap();
+    /// assert_eq!(letters.get(1), Some("b"));
+    /// ```
+    pub fn add_categorical_column(&mut self, categorical: Categorical) -> Result<(), BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut categorical = categorical;
+        self.reset_index(categorical.len())?;
+
+        if categorical.name().is_none() {
+            categorical.set_name(&format!("col_{}", self.n_columns()))
+        }
+
+        let meta = SeriesMeta::from(&categorical);
+        self.data.insert(meta.name.clone(), categorical);
+        self.meta.push(meta);
+
+        Ok(())
+    }
+
+    // Ensure length is a match if we have columns, before adding one of length `len`
+    fn reset_index(&mut self, len: usize) -> Result<(), BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        if self.len() > 0 && self.len() != len {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has length: {}, cannot add series of length: {}",
+                self.len(),
+                len
+            )));
+        } else if self.index.len() != len {
+            self.index = Series::from_vec((0..len as i32).collect::<Vec<I>>())
+        }
+        Ok(())
+    }
+
+    // Add a named column of the same length as the index, leaving the index as is
+    fn insert_column<T: BlackJackData + 'static>(&mut self, series: Series<T>) {
+        let meta = SeriesMeta::from(&series);
+        self.data.insert(meta.name.clone(), series);
+        self.meta.push(meta);
+    }
+
     /// Retrieves a mutable reference to the column
     pub fn get_column_mut<'a, T>(&mut self, name: impl Into<&'a str>) -> Option<&mut Series<T>>
     where
@@ -304,6 +1443,17 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         None
     }
 
+    /// Retrieves a reference to a [`Categorical`] column
+    pub fn get_categorical_column<'a>(&self, name: impl Into<&'a str>) -> Option<&Categorical> {
+        let name = name.into();
+        for meta in &self.meta {
+            if meta.name == name && meta.dtype == DType::CATEGORICAL {
+                return self.data.get(&meta.name);
+            }
+        }
+        None
+    }
+
     /// Get column, infer
     pub fn get_column_infer<'a>(&self, name: impl Into<&'a str>) -> Option<GenericSeriesContainer> {
         let name = name.into();
@@ -322,9 +1472,39 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
                 DType::F32 => {
                     GenericSeriesContainer::F32(self.data.get::<Series<f32>, _>(name)?.clone())
                 }
+                DType::I16 => {
+                    GenericSeriesContainer::I16(self.data.get::<Series<i16>, _>(name)?.clone())
+                }
+                DType::I8 => {
+                    GenericSeriesContainer::I8(self.data.get::<Series<i8>, _>(name)?.clone())
+                }
+                DType::U64 => {
+                    GenericSeriesContainer::U64(self.data.get::<Series<u64>, _>(name)?.clone())
+                }
+                DType::U32 => {
+                    GenericSeriesContainer::U32(self.data.get::<Series<u32>, _>(name)?.clone())
+                }
+                DType::U16 => {
+                    GenericSeriesContainer::U16(self.data.get::<Series<u16>, _>(name)?.clone())
+                }
+                DType::U8 => {
+                    GenericSeriesContainer::U8(self.data.get::<Series<u8>, _>(name)?.clone())
+                }
                 DType::STRING => GenericSeriesContainer::STRING(
                     self.data.get::<Series<String>, _>(name).unwrap().clone(),
                 ),
+                DType::BOOL => {
+                    GenericSeriesContainer::BOOL(self.data.get::<Series<bool>, _>(name)?.clone())
+                }
+                DType::DATETIME => GenericSeriesContainer::DATETIME(
+                    self.data.get::<Series<DateTime>, _>(name)?.clone(),
+                ),
+                DType::DURATION => GenericSeriesContainer::DURATION(
+                    self.data.get::<Series<Duration>, _>(name)?.clone(),
+                ),
+                DType::CATEGORICAL => GenericSeriesContainer::CATEGORICAL(
+                    self.data.get::<Categorical, _>(name)?.clone(),
+                ),
             };
             Some(container)
         } else {
@@ -359,3 +1539,53 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         DataFrameGroupBy::new(groups)
     }
 }
+
+// Compare rows by a key, where `None` is missing and always placed last
+fn by_key<'a, K, F>(key: F, ascending: bool) -> Box<dyn Fn(usize, usize) -> Ordering + 'a>
+where
+    K: PartialOrd,
+    F: Fn(usize) -> Option<K> + 'a,
+{
+    Box::new(move |a, b| match (key(a), key(b)) {
+        (Some(a), Some(b)) if ascending => compare_values(&a, &b),
+        (Some(a), Some(b)) => compare_values(&b, &a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    })
+}
+
+// Convert a numeric series to `f64`, keeping the name and nulls
+fn as_f64<T: BlackJackData + ToPrimitive>(series: &Series<T>) -> Series<f64> {
+    let values = (0..series.len())
+        .map(|idx| series.get(idx).and_then(|value| value.to_f64()))
+        .collect();
+    let mut converted = Series::from_options(values);
+    converted.name = series.name.clone();
+    converted
+}
+
+// Values of a summary, from `Series::describe`, at each of the statistics in `index`
+fn reindex_stats<T: BlackJackData>(
+    index: &Series<String>,
+    stats: &Series<String>,
+    values: &Series<T>,
+) -> Series<T> {
+    let values_at = index
+        .values
+        .iter()
+        .map(|stat| {
+            let pos = stats.values.iter().position(|s| s == stat)?;
+            values.get(pos).cloned()
+        })
+        .collect();
+    let mut series = Series::from_options(values_at);
+    series.name = values.name.clone();
+    series
+}
+
+// The series with the elements in `rows` set to null
+fn null_rows<T: BlackJackData>(series: &Series<T>, rows: &Series<bool>) -> Series<T> {
+    let nulls = Series::from_options(vec![None; series.len()]);
+    series.mask(rows, &nulls).unwrap()
+}
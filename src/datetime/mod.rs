@@ -0,0 +1,868 @@
+//! Datetime and duration dtypes for use in `Series`. Datetimes are stored as nanoseconds
+//! since the Unix epoch with an optional fixed offset from UTC, durations as nanoseconds.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let dt: DateTime = "2019-04-28T13:45:10+02:00".parse().unwrap();
+//!
+//! // Components are in the local time of the offset...
+//! assert_eq!(dt.hour(), 13);
+//! assert_eq!(dt.weekday(), 6);  // Sunday, where Monday == 0
+//!
+//! // ...while the timestamp itself is in UTC
+//! assert_eq!(dt.timestamp_nanos(), 1_556_451_910_000_000_000);
+//! assert_eq!(dt.to_string(), "2019-04-28T13:45:10+02:00");
+//!
+//! // User supplied formats are supported as well
+//! let dt = DateTime::parse_from_str("28/04/2019 13:45", "%d/%m/%Y %H:%M").unwrap();
+//! assert_eq!(dt.format("%Y%m%d"), "20190428");
+//!
+//! // Subtracting datetimes yields a duration, which can be added back
+//! let later = dt + Duration::from_hours(36);
+//! assert_eq!((later - dt).total_seconds(), 129_600.0);
+//! assert_eq!(later.to_string(), "2019-04-30T01:45:00");
+//! ```
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+use num::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A point in time with nanosecond precision, and an optional offset (in seconds east of UTC)
+/// used when displaying it or accessing its components.
+///
+/// Ordering is by the instant in time; two `DateTime`s at the same instant but
+/// with different offsets are not considered equal.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+pub struct DateTime {
+    nanos: i64,
+    offset: Option<i32>,
+}
+
+impl DateTime {
+    /// Create a `DateTime` from nanoseconds since the Unix epoch, without an offset.
+    pub fn from_timestamp_nanos(nanos: i64) -> Self {
+        DateTime {
+            nanos,
+            offset: None,
+        }
+    }
+
+    /// Create a `DateTime` from its calendar components, without an offset.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let dt = DateTime::from_ymd_hms(1970, 1, 2, 0, 0, 0).unwrap();
+    /// assert_eq!(dt.timestamp_nanos(), 86_400_000_000_000);
+    ///
+    /// assert!(DateTime::from_ymd_hms(2019, 2, 29, 0, 0, 0).is_err());
+    /// ```
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, BlackJackError> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return Err(BlackJackError::ValueError(format!(
+                "Invalid date: {}-{}-{}",
+                year, month, day
+            )));
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(BlackJackError::ValueError(format!(
+                "Invalid time: {}:{}:{}",
+                hour, minute, second
+            )));
+        }
+        let time = i64::from(hour) * NANOS_PER_HOUR
+            + i64::from(minute) * NANOS_PER_MINUTE
+            + i64::from(second) * NANOS_PER_SECOND;
+        days_from_civil(year, month, day)
+            .checked_mul(NANOS_PER_DAY)
+            .and_then(|nanos| nanos.checked_add(time))
+            .map(DateTime::from_timestamp_nanos)
+            .ok_or_else(|| BlackJackError::from("Datetime out of range for nanosecond precision"))
+    }
+
+    /// Set the offset from UTC, in seconds, keeping the same instant in time.
+    pub fn with_offset(self, offset: Option<i32>) -> Self {
+        DateTime {
+            nanos: self.nanos,
+            offset,
+        }
+    }
+
+    /// Nanoseconds since the Unix epoch, in UTC.
+    pub fn timestamp_nanos(&self) -> i64 {
+        self.nanos
+    }
+
+    /// Offset from UTC, in seconds, if any.
+    pub fn offset(&self) -> Option<i32> {
+        self.offset
+    }
+
+    /// The calendar year
+    pub fn year(&self) -> i32 {
+        civil_from_days(self.local_days()).0
+    }
+
+    /// The month of the year, starting at 1
+    pub fn month(&self) -> u32 {
+        civil_from_days(self.local_days()).1
+    }
+
+    /// The day of the month, starting at 1
+    pub fn day(&self) -> u32 {
+        civil_from_days(self.local_days()).2
+    }
+
+    /// The day of the week, where Monday == 0 and Sunday == 6
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.local_days() + 3).rem_euclid(7) as u32
+    }
+
+    /// The day of the year, starting at 1
+    pub fn dayofyear(&self) -> u32 {
+        let year = self.year();
+        (self.local_days() - days_from_civil(year, 1, 1) + 1) as u32
+    }
+
+    /// The hour of the day
+    pub fn hour(&self) -> u32 {
+        (self.local_time() / NANOS_PER_HOUR) as u32
+    }
+
+    /// The minute of the hour
+    pub fn minute(&self) -> u32 {
+        (self.local_time() % NANOS_PER_HOUR / NANOS_PER_MINUTE) as u32
+    }
+
+    /// The second of the minute
+    pub fn second(&self) -> u32 {
+        (self.local_time() % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u32
+    }
+
+    /// The fractional part of the second, in nanoseconds
+    pub fn nanosecond(&self) -> u32 {
+        (self.local_time() % NANOS_PER_SECOND) as u32
+    }
+
+    /// Parse a `DateTime` using a `strftime` style format, supported specifiers are:
+    ///
+    /// - `%Y` four digit year, `%y` two digit year
+    /// - `%m` month, `%b` abbreviated month name (ie. `Jan`), `%d` day of the month
+    /// - `%H` hour, `%M` minute, `%S` second, `%f` fractional seconds
+    /// - `%z` offset from UTC (ie. `+0200`, `+02:00` or `Z`)
+    /// - `%%` a literal `%`
+    pub fn parse_from_str(s: &str, format: &str) -> Result<Self, BlackJackError> {
+        let err = || BlackJackError::ValueError(format!("Unable to parse '{}' as '{}'", s, format));
+        let mut cursor = Cursor::new(s);
+        let mut parts = Parts::default();
+        let mut spec = format.chars();
+
+        while let Some(c) = spec.next() {
+            if c != '%' {
+                if !cursor.eat(c) {
+                    return Err(err());
+                }
+                continue;
+            }
+            match spec.next() {
+                Some('Y') => parts.year = cursor.signed_number(4).ok_or_else(err)? as i32,
+                Some('y') => {
+                    let year = cursor.number(2, 2).ok_or_else(err)? as i32;
+                    parts.year = if year < 69 { 2000 + year } else { 1900 + year };
+                }
+                Some('m') => parts.month = Some(cursor.number(1, 2).ok_or_else(err)? as u32),
+                Some('b') => parts.month = Some(cursor.month_abbreviation().ok_or_else(err)?),
+                Some('d') => parts.day = Some(cursor.number(1, 2).ok_or_else(err)? as u32),
+                Some('H') => parts.hour = cursor.number(1, 2).ok_or_else(err)? as u32,
+                Some('M') => parts.minute = cursor.number(1, 2).ok_or_else(err)? as u32,
+                Some('S') => parts.second = cursor.number(1, 2).ok_or_else(err)? as u32,
+                Some('f') => parts.nanos = cursor.fraction().ok_or_else(err)?,
+                Some('z') => parts.offset = Some(cursor.offset().ok_or_else(err)?),
+                Some('%') => {
+                    if !cursor.eat('%') {
+                        return Err(err());
+                    }
+                }
+                _ => {
+                    return Err(BlackJackError::ValueError(format!(
+                        "Unsupported datetime format: '{}'",
+                        format
+                    )))
+                }
+            }
+        }
+        if !cursor.is_done() {
+            return Err(err());
+        }
+        parts.into_datetime()
+    }
+
+    /// Format the `DateTime` using a `strftime` style format, supporting the same
+    /// specifiers as [`DateTime::parse_from_str`]. `%z` is empty if there is no offset.
+    pub fn format(&self, format: &str) -> String {
+        let (year, month, day) = civil_from_days(self.local_days());
+        let mut out = String::with_capacity(format.len() + 16);
+        let mut spec = format.chars();
+
+        while let Some(c) = spec.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            match spec.next() {
+                Some('Y') => out.push_str(&format!("{:04}", year)),
+                Some('y') => out.push_str(&format!("{:02}", year.rem_euclid(100))),
+                Some('m') => out.push_str(&format!("{:02}", month)),
+                Some('b') => out.push_str(MONTH_ABBREVIATIONS[month as usize - 1]),
+                Some('d') => out.push_str(&format!("{:02}", day)),
+                Some('H') => out.push_str(&format!("{:02}", self.hour())),
+                Some('M') => out.push_str(&format!("{:02}", self.minute())),
+                Some('S') => out.push_str(&format!("{:02}", self.second())),
+                Some('f') => out.push_str(&format!("{:09}", self.nanosecond())),
+                Some('z') => {
+                    if let Some(offset) = self.offset {
+                        out.push_str(&format_offset(offset, ""))
+                    }
+                }
+                Some('%') => out.push('%'),
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                }
+                None => out.push('%'),
+            }
+        }
+        out
+    }
+
+    // Nanoseconds since the epoch in the local time of the offset
+    fn local_nanos(&self) -> i64 {
+        self.nanos + i64::from(self.offset.unwrap_or(0)) * NANOS_PER_SECOND
+    }
+
+    fn local_days(&self) -> i64 {
+        self.local_nanos().div_euclid(NANOS_PER_DAY)
+    }
+
+    // Nanoseconds since midnight in the local time of the offset
+    fn local_time(&self) -> i64 {
+        self.local_nanos().rem_euclid(NANOS_PER_DAY)
+    }
+}
+
+/// Formats as ISO-8601, ie. `2019-04-28T13:45:10.5+02:00`
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format("%Y-%m-%dT%H:%M:%S"))?;
+        let nanos = self.nanosecond();
+        if nanos != 0 {
+            write!(f, ".{}", format!("{:09}", nanos).trim_end_matches('0'))?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, "{}", format_offset(offset, ":"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses ISO-8601 dates and datetimes, ie. `2019-04-28`, `2019-04-28 13:45`,
+/// `2019-04-28T13:45:10.123Z` or `2019-04-28T13:45:10+02:00`
+impl FromStr for DateTime {
+    type Err = BlackJackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || BlackJackError::ValueError(format!("Unable to parse '{}' as ISO-8601", s));
+        let mut cursor = Cursor::new(s);
+
+        let year = cursor.signed_number(4).ok_or_else(err)? as i32;
+        cursor.expect('-').ok_or_else(err)?;
+        let month = cursor.number(2, 2).ok_or_else(err)? as u32;
+        cursor.expect('-').ok_or_else(err)?;
+        let day = cursor.number(2, 2).ok_or_else(err)? as u32;
+        let mut parts = Parts {
+            year,
+            month: Some(month),
+            day: Some(day),
+            ..Parts::default()
+        };
+
+        if cursor.eat('T') || cursor.eat(' ') {
+            parts.hour = cursor.number(2, 2).ok_or_else(err)? as u32;
+            cursor.expect(':').ok_or_else(err)?;
+            parts.minute = cursor.number(2, 2).ok_or_else(err)? as u32;
+            if cursor.eat(':') {
+                parts.second = cursor.number(2, 2).ok_or_else(err)? as u32;
+                if cursor.eat('.') || cursor.eat(',') {
+                    parts.nanos = cursor.fraction().ok_or_else(err)?;
+                }
+            }
+            if !cursor.is_done() {
+                parts.offset = Some(cursor.offset().ok_or_else(err)?);
+            }
+        }
+        if !cursor.is_done() {
+            return Err(err());
+        }
+        parts.into_datetime()
+    }
+}
+
+impl BlackJackData for DateTime {
+    fn dtype(&self) -> DType {
+        DType::DATETIME
+    }
+
+    impl_hash_by_value!();
+}
+
+/// Datetime specific methods for `Series<DateTime>`, each keeping the series name
+/// and nulls.
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let series = Series::from_vec(vec![
+///     "2019-04-28T13:45:10".to_string(),
+///     "2020-01-01T00:00:00".to_string(),
+/// ]);
+/// let series = series.to_datetime(None).unwrap();
+///
+/// assert_eq!(series.year().values, vec![2019, 2020]);
+/// assert_eq!(series.weekday().values, vec![6, 2]);
+/// assert_eq!(series.strftime("%d.%m.%Y").values, vec!["28.04.2019", "01.01.2020"]);
+/// ```
+impl Series<DateTime> {
+    /// The calendar year of each element
+    pub fn year(&self) -> Series<i32> {
+        self.component(|dt| dt.year())
+    }
+
+    /// The month of each element, starting at 1
+    pub fn month(&self) -> Series<i32> {
+        self.component(|dt| dt.month() as i32)
+    }
+
+    /// The day of the month of each element, starting at 1
+    pub fn day(&self) -> Series<i32> {
+        self.component(|dt| dt.day() as i32)
+    }
+
+    /// The day of the week of each element, where Monday == 0 and Sunday == 6
+    pub fn weekday(&self) -> Series<i32> {
+        self.component(|dt| dt.weekday() as i32)
+    }
+
+    /// The hour of each element
+    pub fn hour(&self) -> Series<i32> {
+        self.component(|dt| dt.hour() as i32)
+    }
+
+    /// The minute of each element
+    pub fn minute(&self) -> Series<i32> {
+        self.component(|dt| dt.minute() as i32)
+    }
+
+    /// The second of each element
+    pub fn second(&self) -> Series<i32> {
+        self.component(|dt| dt.second() as i32)
+    }
+
+    /// Format each element with a `strftime` style format, see [`DateTime::format`]
+    pub fn strftime(&self, format: &str) -> Series<String> {
+        self.component(|dt| dt.format(format))
+    }
+
+    fn component<B, F>(&self, func: F) -> Series<B>
+    where
+        B: BlackJackData,
+        F: Fn(&DateTime) -> B,
+    {
+        let values = (0..self.len())
+            .map(|idx| self.get(idx).map(&func))
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series
+    }
+}
+
+impl Series<String> {
+    /// Parse a `Series<String>` into a `Series<DateTime>`, using ISO-8601 if no
+    /// `format` is given, otherwise see [`DateTime::parse_from_str`].
+    /// Nulls are kept, any other element failing to parse is an error.
+    pub fn to_datetime(&self, format: Option<&str>) -> Result<Series<DateTime>, BlackJackError> {
+        let values = (0..self.len())
+            .map(|idx| match self.get(idx) {
+                Some(s) => match format {
+                    Some(format) => DateTime::parse_from_str(s, format).map(Some),
+                    None => s.parse::<DateTime>().map(Some),
+                },
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<Option<DateTime>>, _>>()?;
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        Ok(series)
+    }
+}
+
+/// Difference between two instants in time, with nanosecond precision.
+///
+/// Aggregations of a `Series<Duration>` which yield an `f64`, ie. [`Series::mean`],
+/// are in nanoseconds; see [`Duration::from_nanos`].
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let start = Series::from_vec(vec!["2019-01-01".parse::<DateTime>().unwrap(); 2]);
+/// let end = Series::from_vec(vec![
+///     "2019-01-02".parse::<DateTime>().unwrap(),
+///     "2019-01-01T12:00:00".parse::<DateTime>().unwrap(),
+/// ]);
+///
+/// let elapsed: Series<Duration> = (end - start).unwrap();
+/// assert_eq!(elapsed.max().unwrap(), Duration::from_days(1));
+/// assert_eq!(elapsed.sum(), Duration::from_hours(36));
+/// assert_eq!(elapsed.mean_duration().unwrap(), Duration::from_hours(18));
+/// assert_eq!(elapsed.total_seconds().values, vec![86_400.0, 43_200.0]);
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+pub struct Duration {
+    nanos: i64,
+}
+
+impl Duration {
+    /// Create a `Duration` of a number of nanoseconds
+    pub fn from_nanos(nanos: i64) -> Self {
+        Duration { nanos }
+    }
+
+    /// Create a `Duration` of a number of seconds
+    pub fn from_seconds(seconds: i64) -> Self {
+        Duration::from_nanos(seconds * NANOS_PER_SECOND)
+    }
+
+    /// Create a `Duration` of a number of minutes
+    pub fn from_minutes(minutes: i64) -> Self {
+        Duration::from_nanos(minutes * NANOS_PER_MINUTE)
+    }
+
+    /// Create a `Duration` of a number of hours
+    pub fn from_hours(hours: i64) -> Self {
+        Duration::from_nanos(hours * NANOS_PER_HOUR)
+    }
+
+    /// Create a `Duration` of a number of days
+    pub fn from_days(days: i64) -> Self {
+        Duration::from_nanos(days * NANOS_PER_DAY)
+    }
+
+    /// Total length of the duration in nanoseconds
+    pub fn total_nanos(&self) -> i64 {
+        self.nanos
+    }
+
+    /// Total length of the duration in seconds
+    pub fn total_seconds(&self) -> f64 {
+        self.nanos as f64 / NANOS_PER_SECOND as f64
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        Duration::from_nanos(self.nanos + other.nanos)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        Duration::from_nanos(self.nanos - other.nanos)
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        Duration::from_nanos(-self.nanos)
+    }
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Duration {
+        Duration::from_nanos(iter.map(|d| d.nanos).sum())
+    }
+}
+
+/// Support `datetime - datetime`
+impl Sub for DateTime {
+    type Output = Duration;
+
+    fn sub(self, other: DateTime) -> Duration {
+        Duration::from_nanos(self.nanos - other.nanos)
+    }
+}
+
+/// Support `datetime + duration`, keeping the offset of the datetime
+impl Add<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, duration: Duration) -> DateTime {
+        DateTime::from_timestamp_nanos(self.nanos + duration.nanos).with_offset(self.offset)
+    }
+}
+
+/// Support `datetime - duration`, keeping the offset of the datetime
+impl Sub<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, duration: Duration) -> DateTime {
+        self + -duration
+    }
+}
+
+/// Durations as nanoseconds
+impl ToPrimitive for Duration {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.nanos)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.nanos.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.nanos as f64)
+    }
+}
+
+/// Formats as `[-]<days> days <hours>:<minutes>:<seconds>[.<fraction>]`, ie. `1 days 02:30:00.5`
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.nanos < 0 { "-" } else { "" };
+        let nanos = self.nanos.unsigned_abs();
+        let (days, time) = (nanos / NANOS_PER_DAY as u64, nanos % NANOS_PER_DAY as u64);
+        write!(
+            f,
+            "{}{} days {:02}:{:02}:{:02}",
+            sign,
+            days,
+            time / NANOS_PER_HOUR as u64,
+            time % NANOS_PER_HOUR as u64 / NANOS_PER_MINUTE as u64,
+            time % NANOS_PER_MINUTE as u64 / NANOS_PER_SECOND as u64
+        )?;
+        let fraction = time % NANOS_PER_SECOND as u64;
+        if fraction != 0 {
+            write!(f, ".{}", format!("{:09}", fraction).trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the format written by `Display`, where the days are optional; ie. `12:00:00`
+impl FromStr for Duration {
+    type Err = BlackJackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || BlackJackError::ValueError(format!("Unable to parse '{}' as a duration", s));
+        let mut cursor = Cursor::new(s);
+
+        let sign = if cursor.eat('-') { -1 } else { 1 };
+        let leading = cursor.number(1, 18).ok_or_else(err)?;
+        let (days, hours) = if cursor.eat(' ') {
+            if !(cursor.eat_str("days ") || cursor.eat_str("day ")) {
+                return Err(err());
+            }
+            (leading, cursor.number(2, 2).ok_or_else(err)?)
+        } else {
+            (0, leading)
+        };
+        cursor.expect(':').ok_or_else(err)?;
+        let minutes = cursor.number(2, 2).ok_or_else(err)?;
+        cursor.expect(':').ok_or_else(err)?;
+        let seconds = cursor.number(2, 2).ok_or_else(err)?;
+        let fraction = if cursor.eat('.') {
+            cursor.fraction().ok_or_else(err)?
+        } else {
+            0
+        };
+        if !cursor.is_done() || minutes > 59 || seconds > 59 {
+            return Err(err());
+        }
+        // Days and hours may be too many to represent in nanoseconds
+        let nanos = days
+            .checked_mul(NANOS_PER_DAY)
+            .and_then(|nanos| nanos.checked_add(hours.checked_mul(NANOS_PER_HOUR)?))
+            .and_then(|nanos| {
+                nanos
+                    .checked_add(minutes * NANOS_PER_MINUTE + seconds * NANOS_PER_SECOND + fraction)
+            })
+            .ok_or_else(|| {
+                BlackJackError::ValueError(format!("Duration '{}' is out of range", s))
+            })?;
+        Ok(Duration::from_nanos(sign * nanos))
+    }
+}
+
+impl BlackJackData for Duration {
+    fn dtype(&self) -> DType {
+        DType::DURATION
+    }
+
+    impl_hash_by_value!();
+}
+
+impl Series<Duration> {
+    /// Total length of each element in seconds
+    pub fn total_seconds(&self) -> Series<f64> {
+        let values = (0..self.len())
+            .map(|idx| self.get(idx).map(|d| d.total_seconds()))
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series
+    }
+
+    /// Mean of the series as a `Duration`, rounded to the nearest nanosecond;
+    /// [`Series::mean`] yields the mean in nanoseconds.
+    pub fn mean_duration(&self) -> Result<Duration, BlackJackError> {
+        if self.count() == 0 {
+            return Err(BlackJackError::from(
+                "Cannot compute mean of an empty series!",
+            ));
+        }
+        Ok(Duration::from_nanos(self.mean()?.round() as i64))
+    }
+}
+
+// Components collected while parsing
+#[derive(Default)]
+struct Parts {
+    year: i32,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: i64,
+    offset: Option<i32>,
+}
+
+impl Parts {
+    fn into_datetime(self) -> Result<DateTime, BlackJackError> {
+        let local = DateTime::from_ymd_hms(
+            self.year,
+            self.month.unwrap_or(1),
+            self.day.unwrap_or(1),
+            self.hour,
+            self.minute,
+            self.second,
+        )?;
+        let offset = i64::from(self.offset.unwrap_or(0)) * NANOS_PER_SECOND;
+        let nanos = local
+            .nanos
+            .checked_add(self.nanos)
+            .and_then(|nanos| nanos.checked_sub(offset))
+            .ok_or_else(|| {
+                BlackJackError::from("Datetime out of range for nanosecond precision")
+            })?;
+        Ok(DateTime::from_timestamp_nanos(nanos).with_offset(self.offset))
+    }
+}
+
+// Simple cursor over the string being parsed
+struct Cursor<'a> {
+    s: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str) -> Self {
+        Cursor { s }
+    }
+
+    fn is_done(&self) -> bool {
+        self.s.is_empty()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.s.starts_with(c) {
+            self.s = &self.s[c.len_utf8()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, prefix: &str) -> bool {
+        if self.s.starts_with(prefix) {
+            self.s = &self.s[prefix.len()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        if self.eat(c) {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    // Read between `min` and `max` ascii digits
+    fn number(&mut self, min: usize, max: usize) -> Option<i64> {
+        let len = self
+            .s
+            .bytes()
+            .take(max)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len < min {
+            return None;
+        }
+        let number = self.s[..len].parse().ok()?;
+        self.s = &self.s[len..];
+        Some(number)
+    }
+
+    fn signed_number(&mut self, digits: usize) -> Option<i64> {
+        let sign = if self.eat('-') { -1 } else { 1 };
+        self.number(digits, digits).map(|n| sign * n)
+    }
+
+    // Fractional seconds of up to nanosecond precision, as nanoseconds
+    fn fraction(&mut self) -> Option<i64> {
+        let len = self.s.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let digits = &self.s[..len.min(9)];
+        let nanos = digits.parse::<i64>().ok()? * 10_i64.pow(9 - digits.len() as u32);
+        self.s = &self.s[len..];
+        Some(nanos)
+    }
+
+    // Offset from UTC in seconds; `Z`, `+HH`, `+HHMM` or `+HH:MM`
+    fn offset(&mut self) -> Option<i32> {
+        if self.eat('Z') {
+            return Some(0);
+        }
+        let sign = if self.eat('+') {
+            1
+        } else if self.eat('-') {
+            -1
+        } else {
+            return None;
+        };
+        let hours = self.number(2, 2)?;
+        // Minutes are optional, unless separated from the hours
+        let minutes = if self.eat(':') {
+            self.number(2, 2)?
+        } else {
+            self.number(2, 2).unwrap_or(0)
+        };
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        Some(sign * (hours * 3600 + minutes * 60) as i32)
+    }
+
+    fn month_abbreviation(&mut self) -> Option<u32> {
+        let candidate = self.s.get(..3)?;
+        let month = MONTH_ABBREVIATIONS
+            .iter()
+            .position(|m| m.eq_ignore_ascii_case(candidate))?;
+        self.s = &self.s[3..];
+        Some(month as u32 + 1)
+    }
+}
+
+fn format_offset(offset: i32, separator: &str) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    format!(
+        "{}{:02}{}{:02}",
+        sign,
+        offset / 3600,
+        separator,
+        offset % 3600 / 60
+    )
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 of a proleptic Gregorian date.
+// See: http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(if month <= 2 { year - 1 } else { year });
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Proleptic Gregorian (year, month, day) of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month, day)
+}
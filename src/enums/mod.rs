@@ -17,8 +17,196 @@ pub enum DType {
     /// `i32`
     I32,
 
+    /// `i16`
+    I16,
+
+    /// `i8`
+    I8,
+
+    /// `u64`
+    U64,
+
+    /// `u32`
+    U32,
+
+    /// `u16`
+    U16,
+
+    /// `u8`
+    U8,
+
     /// `String`
     STRING,
+
+    /// `bool`
+    BOOL,
+
+    /// [`DateTime`]
+    DATETIME,
+
+    /// [`Duration`]
+    DURATION,
+
+    /// [`Categorical`], dictionary encoded strings
+    CATEGORICAL,
+}
+
+impl DType {
+    /// The dtype arithmetic between `self` and `other` promotes to, `None` if either
+    /// isn't numeric. Integers of the same signedness promote to the wider one, and
+    /// signed with unsigned to a signed integer wider than the unsigned one, or `f64`
+    /// for `u64`. Floats with integers of up to 16 bits promote to the wider float,
+    /// and with wider integers to `f64`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// assert_eq!(DType::I32.promote(&DType::F64), Some(DType::F64));
+    /// assert_eq!(DType::I32.promote(&DType::I64), Some(DType::I64));
+    /// assert_eq!(DType::U8.promote(&DType::I8), Some(DType::I16));
+    /// assert_eq!(DType::U16.promote(&DType::F32), Some(DType::F32));
+    /// assert_eq!(DType::STRING.promote(&DType::I32), None);
+    /// ```
+    pub fn promote(&self, other: &DType) -> Option<DType> {
+        let (left, right) = (self.numeric_kind()?, other.numeric_kind()?);
+        let promoted = if left == right {
+            left
+        } else if left.0 == 'f' || right.0 == 'f' {
+            let float_bits = |(kind, bits): (char, u32)| match kind {
+                'f' => bits,
+                _ if bits <= 16 => 32,
+                _ => 64,
+            };
+            ('f', float_bits(left).max(float_bits(right)))
+        } else if left.0 == right.0 {
+            (left.0, left.1.max(right.1))
+        } else {
+            let (signed, unsigned) = if left.0 == 'i' {
+                (left.1, right.1)
+            } else {
+                (right.1, left.1)
+            };
+            if signed > unsigned {
+                ('i', signed)
+            } else if unsigned < 64 {
+                ('i', unsigned * 2)
+            } else {
+                ('f', 64)
+            }
+        };
+        NUMERIC_KINDS
+            .iter()
+            .find(|(_, kind, bits)| (*kind, *bits) == promoted)
+            .map(|(dtype, _, _)| dtype.clone())
+    }
+
+    // Kind and width of a numeric dtype, see `NUMERIC_KINDS`
+    fn numeric_kind(&self) -> Option<(char, u32)> {
+        NUMERIC_KINDS
+            .iter()
+            .find(|(dtype, _, _)| dtype == self)
+            .map(|(_, kind, bits)| (*kind, *bits))
+    }
+}
+
+// Numeric dtypes by kind, float `'f'`, signed `'i'` or unsigned `'u'`, and width in bits
+const NUMERIC_KINDS: [(DType, char, u32); 10] = [
+    (DType::F64, 'f', 64),
+    (DType::I64, 'i', 64),
+    (DType::F32, 'f', 32),
+    (DType::I32, 'i', 32),
+    (DType::I16, 'i', 16),
+    (DType::I8, 'i', 8),
+    (DType::U64, 'u', 64),
+    (DType::U32, 'u', 32),
+    (DType::U16, 'u', 16),
+    (DType::U8, 'u', 8),
+];
+
+/// Where to place missing values when sorting, see [`SortOptions`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NaPosition {
+    /// Before all other values
+    First,
+
+    /// After all other values
+    Last,
+}
+
+/// How [`Series::interpolate`] estimates missing values from their neighbours
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InterpolationMethod {
+    /// Straight line between the surrounding values
+    Linear,
+
+    /// Value of the closest element by position, the earlier one on ties
+    Nearest,
+
+    /// Natural cubic spline through all values, requires at least three
+    CubicSpline,
+}
+
+/// How [`Series::rank`] ranks groups of equal values
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RankMethod {
+    /// Average of the ranks the group would take
+    Average,
+
+    /// Lowest rank in the group
+    Min,
+
+    /// Highest rank in the group
+    Max,
+
+    /// Like `Min`, but ranks always increase by one between groups
+    Dense,
+
+    /// Ranks assigned in the order the values appear
+    First,
+}
+
+/// How [`Series::corr`] measures the correlation between two series
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CorrelationMethod {
+    /// Linear correlation of the values
+    Pearson,
+
+    /// Pearson correlation of the ranks of the values, ties taking their average rank
+    Spearman,
+
+    /// Kendall's tau-b, from the number of concordant and discordant pairs, adjusted for ties
+    Kendall,
+}
+
+/// Which bounds [`Series::between`] includes
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Inclusive {
+    /// Both the lower and upper bound
+    Both,
+
+    /// Neither bound
+    Neither,
+
+    /// Only the lower bound
+    Left,
+
+    /// Only the upper bound
+    Right,
+}
+
+/// Which of a group of duplicate values [`Series::duplicated`] doesn't mark, and
+/// so [`Series::drop_duplicates`] keeps
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Keep {
+    /// The first occurrence
+    First,
+
+    /// The last occurrence
+    Last,
+
+    /// None of them, every occurrence of a value which appears more than once
+    None,
 }
 
 /// Container for use with `Row` struct
@@ -36,8 +224,35 @@ pub enum Datum<'a> {
     /// Refrence to a i32 within the dataframe
     I32(&'a i32),
 
+    /// Refrence to a i16 within the dataframe
+    I16(&'a i16),
+
+    /// Refrence to a i8 within the dataframe
+    I8(&'a i8),
+
+    /// Refrence to a u64 within the dataframe
+    U64(&'a u64),
+
+    /// Refrence to a u32 within the dataframe
+    U32(&'a u32),
+
+    /// Refrence to a u16 within the dataframe
+    U16(&'a u16),
+
+    /// Refrence to a u8 within the dataframe
+    U8(&'a u8),
+
     /// Refrence to a String within the dataframe
     STR(&'a String),
+
+    /// Refrence to a bool within the dataframe
+    BOOL(&'a bool),
+
+    /// Refrence to a DateTime within the dataframe
+    DATETIME(&'a DateTime),
+
+    /// Refrence to a Duration within the dataframe
+    DURATION(&'a Duration),
 }
 
 /// An enum representation of a `Series`, typically only seen
@@ -56,6 +271,36 @@ pub enum Column {
     /// A column in the `DataFrame` of type `Series<i32>`
     I32(Series<i32>),
 
+    /// A column in the `DataFrame` of type `Series<i16>`
+    I16(Series<i16>),
+
+    /// A column in the `DataFrame` of type `Series<i8>`
+    I8(Series<i8>),
+
+    /// A column in the `DataFrame` of type `Series<u64>`
+    U64(Series<u64>),
+
+    /// A column in the `DataFrame` of type `Series<u32>`
+    U32(Series<u32>),
+
+    /// A column in the `DataFrame` of type `Series<u16>`
+    U16(Series<u16>),
+
+    /// A column in the `DataFrame` of type `Series<u8>`
+    U8(Series<u8>),
+
     /// A column in the `DataFrame` of type `Series<String>`
     STR(Series<String>),
+
+    /// A column in the `DataFrame` of type `Series<bool>`
+    BOOL(Series<bool>),
+
+    /// A column in the `DataFrame` of type `Series<DateTime>`
+    DATETIME(Series<DateTime>),
+
+    /// A column in the `DataFrame` of type `Series<Duration>`
+    DURATION(Series<Duration>),
+
+    /// A column in the `DataFrame` of type [`Categorical`]
+    CATEGORICAL(Categorical),
 }
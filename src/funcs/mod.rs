@@ -29,10 +29,100 @@ where
     Some(var.sqrt())
 }
 
+/// Calculate the skewness, the population skewness if `bias`, otherwise the sample
+/// skewness adjusted for bias, which requires at least 3 values.
+pub fn skew<T>(values: &[T], bias: bool) -> Option<f64>
+where
+    T: ToPrimitive,
+{
+    let n = values.len() as f64;
+    let (m2, m3) = (central_moment(values, 2)?, central_moment(values, 3)?);
+    let skew = m3 / m2.powf(1.5);
+    if bias {
+        Some(skew)
+    } else if n >= 3. {
+        Some(skew * (n * (n - 1.)).sqrt() / (n - 2.))
+    } else {
+        None
+    }
+}
+
+/// Calculate the excess kurtosis, the population kurtosis if `bias`, otherwise the
+/// sample kurtosis adjusted for bias, which requires at least 4 values.
+pub fn kurtosis<T>(values: &[T], bias: bool) -> Option<f64>
+where
+    T: ToPrimitive,
+{
+    let n = values.len() as f64;
+    let (m2, m4) = (central_moment(values, 2)?, central_moment(values, 4)?);
+    let kurtosis = m4 / m2.powi(2) - 3.;
+    if bias {
+        Some(kurtosis)
+    } else if n >= 4. {
+        Some(((n + 1.) * kurtosis + 6.) * (n - 1.) / ((n - 2.) * (n - 3.)))
+    } else {
+        None
+    }
+}
+
+/// Calculate the standard error of the mean where
+/// `ddof` is either 0_f64 or 1_f64 for population or sample variance.
+pub fn sem<T>(values: &[T], ddof: f64) -> Option<f64>
+where
+    T: Num + ToPrimitive,
+{
+    Some(std(values, ddof)? / (values.len() as f64).sqrt())
+}
+
+/// Calculate the mean absolute deviation from the mean; `NaN` if any value is `NaN`
+pub fn mean_abs_dev<T>(values: &[T]) -> Option<f64>
+where
+    T: ToPrimitive,
+{
+    if values.is_empty() {
+        return None;
+    }
+    let m = mean(values)?;
+    mean(&to_f64(values).map(|v| (v - m).abs()).collect::<Vec<f64>>())
+}
+
+/// Calculate the median absolute deviation from the median; `NaN` if any value is `NaN`
+pub fn median_abs_dev<T>(values: &[T]) -> Option<f64>
+where
+    T: ToPrimitive,
+{
+    if to_f64(values).any(f64::is_nan) {
+        return Some(f64::NAN);
+    }
+    let median = stats::median(to_f64(values))?;
+    stats::median(to_f64(values).map(|v| (v - median).abs()))
+}
+
+// The `k`th moment about the mean, divided by the number of values; `NaN` if any
+// value is `NaN`
+fn central_moment<T>(values: &[T], k: i32) -> Option<f64>
+where
+    T: ToPrimitive,
+{
+    if values.is_empty() {
+        return None;
+    }
+    let m = mean(values)?;
+    mean(
+        &to_f64(values)
+            .map(|v| (v - m).powi(k))
+            .collect::<Vec<f64>>(),
+    )
+}
+
+fn to_f64<T: ToPrimitive>(values: &[T]) -> impl Iterator<Item = f64> + '_ {
+    values.iter().map(|v| v.to_f64().unwrap())
+}
+
 /// Calculate mean / average
 pub fn mean<T>(values: &[T]) -> Option<f64>
 where
-    T: Num + ToPrimitive,
+    T: ToPrimitive,
 {
     Some(values.iter().map(|v| v.to_f64().unwrap()).sum::<f64>() / values.len() as f64)
 }
@@ -40,7 +130,7 @@ where
 /// Calculate sum
 pub fn sum<T>(values: &[T]) -> T
 where
-    T: Num + Copy + Sum,
+    T: Copy + Sum,
 {
     values.iter().map(|v| *v).sum()
 }
@@ -48,7 +138,7 @@ where
 /// Calculate min
 pub fn min<T>(values: &[T]) -> Option<&T>
 where
-    T: Num + PartialOrd + Copy,
+    T: PartialOrd + Copy,
 {
     values.iter().min_by(|a, b| match a.partial_cmp(b) {
         Some(Ordering::Less) => Ordering::Less,
@@ -61,7 +151,7 @@ where
 /// Calculate max
 pub fn max<T>(values: &[T]) -> Option<&T>
 where
-    T: Num + PartialOrd + Copy,
+    T: PartialOrd + Copy,
 {
     values.iter().min_by(|a, b| match b.partial_cmp(a) {
         Some(Ordering::Less) => Ordering::Less,
@@ -48,6 +48,7 @@
 pub mod macros;
 
 pub mod dataframe;
+pub mod datetime;
 pub mod enums;
 pub mod error;
 mod funcs;
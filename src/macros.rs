@@ -16,25 +16,41 @@ macro_rules! impl_series_into_iter {
     };
 }
 
+/// Implement the hashing methods of [`BlackJackData`] for a dtype which is `Hash + Eq`,
+/// from within its `impl BlackJackData` block
+#[macro_export]
+macro_rules! impl_hash_by_value {
+    () => {
+        fn hash_value<H: std::hash::Hasher>(&self, state: &mut H) {
+            std::hash::Hash::hash(self, state)
+        }
+
+        fn eq_value(&self, other: &Self) -> bool {
+            self == other
+        }
+    };
+}
+
 /// Implement various inplace numeric operations for a Series
-/// ie. `series += 1`
+/// ie. `series += other_series`, panicking if the lengths differ
 #[macro_export]
 macro_rules! impl_series_by_series_op_inplace {
-
-    // Use: impl_series_by_series_op_inplace!(MulAssign, mul_assign, *=)
-    ($operation:ident, $func_name:ident, $op:tt) => {
+    // Use: impl_series_by_series_op_inplace!(MulAssign, mul_assign, try_mul_assign)
+    ($operation:ident, $func_name:ident, $try_func_name:ident) => {
+        /// Support `series += other_series` and friends
+        ///
+        /// ## Panics
+        /// If the lengths of the series differ; the `try_` method of the same name,
+        /// ie. `try_add_assign`, returns a `LengthMismatch` error instead.
         impl<T> $operation<Series<T>> for Series<T>
-            where T: BlackJackData + $operation
+        where
+            T: BlackJackData + $operation,
         {
             fn $func_name(&mut self, other: Series<T>) {
-                let _ = self.values
-                    .iter_mut()
-                    .zip(other.values.into_iter())
-                    .map(|(v, o)| *v $op o)
-                    .collect::<Vec<()>>();
+                self.$try_func_name(other).unwrap()
             }
         }
-    }
+    };
 }
 
 /// Implement series by series operations
@@ -58,12 +74,20 @@ macro_rules! impl_series_by_series_op {
                         format!("Source series is of size: {}, and other is of size: {}", &self.len(), &other.len())
                     ))
                 } else {
+                    // Null slots are skipped, as their default may not be a valid operand
+                    let validity = self.combined_validity(&other);
+                    let nulls = (0..self.len())
+                        .map(|idx| self.is_null(idx) || other.is_null(idx))
+                        .collect::<Vec<bool>>();
                     let result = self.values
                         .into_iter()
                         .zip(other.values.into_iter())
-                        .map(|(x1, x2)| x1 $op x2)
+                        .zip(nulls)
+                        .map(|((x1, x2), null)| if null { T::default() } else { x1 $op x2 })
                         .collect();
-                    Ok(Series::from_vec(result))
+                    let mut series = Series::from_vec(result);
+                    series.validity = validity;
+                    Ok(series)
                 }
 
 
@@ -72,3 +96,142 @@ macro_rules! impl_series_by_series_op {
 
     }
 }
+
+/// Implement series by series operations between two numeric dtypes, both ways round,
+/// promoting the elements to a common dtype; ie. `Series<i32> + Series<f64>`
+#[macro_export]
+macro_rules! impl_series_promotion {
+
+    // Use: impl_series_promotion!(i32, f64 => f64)
+    ($left:ty, $right:ty => $out:ty) => {
+        impl_series_promotion!(@ops $left, $right => $out);
+        impl_series_promotion!(@ops $right, $left => $out);
+    };
+
+    (@ops $left:ty, $right:ty => $out:ty) => {
+        impl_series_promotion!(@op $left, $right => $out, Add, add, +);
+        impl_series_promotion!(@op $left, $right => $out, Sub, sub, -);
+        impl_series_promotion!(@op $left, $right => $out, Mul, mul, *);
+        impl_series_promotion!(@op $left, $right => $out, Div, div, /);
+    };
+
+    (@op $left:ty, $right:ty => $out:ty, $operation:ident, $func_name:ident, $op:tt) => {
+
+        /// Support `series + other_series` of another dtype
+        impl $operation<Series<$right>> for Series<$left> {
+            type Output = Result<Series<$out>, BlackJackError>;
+
+            fn $func_name(self, other: Series<$right>) -> Self::Output {
+                zip_elements(self, other, |a, b| (a as $out) $op (b as $out))
+            }
+        }
+    };
+}
+
+/// Implement series by scalar operations for a scalar of another numeric dtype,
+/// promoting the elements to a common dtype; ie. `Series<i32> * 0.5`
+#[macro_export]
+macro_rules! impl_scalar_promotion {
+
+    // Use: impl_scalar_promotion!(i32, f64 => f64)
+    ($series:ty, $scalar:ty => $out:ty) => {
+        impl_scalar_promotion!(@op $series, $scalar => $out, Add, add, +);
+        impl_scalar_promotion!(@op $series, $scalar => $out, Sub, sub, -);
+        impl_scalar_promotion!(@op $series, $scalar => $out, Mul, mul, *);
+        impl_scalar_promotion!(@op $series, $scalar => $out, Div, div, /);
+    };
+
+    (@op $series:ty, $scalar:ty => $out:ty, $operation:ident, $func_name:ident, $op:tt) => {
+
+        /// Support `series + scalar` of another dtype
+        impl $operation<$scalar> for Series<$series> {
+            type Output = Series<$out>;
+
+            fn $func_name(self, scalar_val: $scalar) -> Series<$out> {
+                let values = self.values
+                    .iter()
+                    .map(|v| (*v as $out) $op (scalar_val as $out))
+                    .collect();
+                let mut series = Series::from_vec(values);
+                series.name = self.name;
+                series.validity = self.validity;
+                series
+            }
+        }
+    };
+}
+
+/// Implement operations between two [`GenericSeriesContainer`]s of numeric dtypes,
+/// promoting both to a common dtype; see [`DType::promote`]
+#[macro_export]
+macro_rules! impl_container_op {
+
+    // Use: impl_container_op!(Add, add, +)
+    ($operation:ident, $func_name:ident, $op:tt) => {
+
+        /// Support `container + other_container`, a `ValueError` if either isn't numeric
+        impl $operation for GenericSeriesContainer {
+            type Output = Result<GenericSeriesContainer, BlackJackError>;
+
+            fn $func_name(self, other: GenericSeriesContainer) -> Self::Output {
+                let (left_dtype, right_dtype) = (self.dtype(), other.dtype());
+                let promoted = left_dtype.promote(&right_dtype).ok_or_else(|| {
+                    BlackJackError::ValueError(format!(
+                        "Unable to apply {} to series of dtypes {:?} and {:?}",
+                        stringify!($func_name), left_dtype, right_dtype
+                    ))
+                })?;
+                match (self.cast(&promoted), other.cast(&promoted)) {
+                    (Some(GenericSeriesContainer::I64(left)), Some(GenericSeriesContainer::I64(right))) => {
+                        Ok(GenericSeriesContainer::I64((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::F64(left)), Some(GenericSeriesContainer::F64(right))) => {
+                        Ok(GenericSeriesContainer::F64((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::I32(left)), Some(GenericSeriesContainer::I32(right))) => {
+                        Ok(GenericSeriesContainer::I32((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::F32(left)), Some(GenericSeriesContainer::F32(right))) => {
+                        Ok(GenericSeriesContainer::F32((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::I16(left)), Some(GenericSeriesContainer::I16(right))) => {
+                        Ok(GenericSeriesContainer::I16((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::I8(left)), Some(GenericSeriesContainer::I8(right))) => {
+                        Ok(GenericSeriesContainer::I8((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::U64(left)), Some(GenericSeriesContainer::U64(right))) => {
+                        Ok(GenericSeriesContainer::U64((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::U32(left)), Some(GenericSeriesContainer::U32(right))) => {
+                        Ok(GenericSeriesContainer::U32((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::U16(left)), Some(GenericSeriesContainer::U16(right))) => {
+                        Ok(GenericSeriesContainer::U16((left $op right)?))
+                    }
+                    (Some(GenericSeriesContainer::U8(left)), Some(GenericSeriesContainer::U8(right))) => {
+                        Ok(GenericSeriesContainer::U8((left $op right)?))
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+/// Implement `describe` for a numeric dtype of `Series`, summarising it by count,
+/// mean, std, min, quartiles and max
+#[macro_export]
+macro_rules! impl_describe_numeric {
+    // Use: impl_describe_numeric!(f64)
+    ($primitive:ty) => {
+        impl Series<$primitive> {
+            /// Summarise the series; the count of present values, their mean, sample
+            /// standard deviation, min, 25%, 50% and 75% quantiles and max. All but the
+            /// count are null for an empty series, and the std for a single value.
+            pub fn describe(&self) -> (Series<String>, Series<f64>) {
+                self.describe_numeric()
+            }
+        }
+    };
+}
@@ -1,6 +1,7 @@
 //! Default and recommended imports for functionality of crate.
 
 pub use crate::dataframe::*;
+pub use crate::datetime::*;
 pub use crate::enums::*;
 pub use crate::error::*;
 pub use crate::row::*;
@@ -0,0 +1,172 @@
+//! Label aligned arithmetic between `Series`, matching elements by the labels of
+//! an index, such as [`DataFrame::index`], rather than by position.
+//!
+//! The result is labelled by the labels of the left series in order, followed by
+//! any labels only found on the right. Where a label, or its value, is missing on
+//! one side, the fill value stands in for it; without one the result is null.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let mut left = DataFrame::new();
+//! left.add_column(Series::from_vec(vec![1., 2.])).unwrap();
+//! left.set_index(Series::from_vec(vec![10, 20])).unwrap();
+//!
+//! let mut right = DataFrame::new();
+//! right.add_column(Series::from_vec(vec![5., 7.])).unwrap();
+//! right.set_index(Series::from_vec(vec![30, 10])).unwrap();
+//!
+//! let a: &Series<f64> = left.get_column("col_0").unwrap();
+//! let b: &Series<f64> = right.get_column("col_0").unwrap();
+//!
+//! let (labels, sum) = a.add_aligned(left.index(), b, right.index(), Some(0.)).unwrap();
+//! assert_eq!(labels.values, vec![10, 20, 30]);
+//! assert_eq!(sum.values, vec![8., 2., 5.]);
+//!
+//! let (_, sum) = a.add_aligned(left.index(), b, right.index(), None).unwrap();
+//! assert_eq!(sum.null_count(), 2);
+//! ```
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use indexmap::IndexMap;
+
+use crate::prelude::*;
+use crate::traits::HashKey;
+
+impl<T> Series<T>
+where
+    T: BlackJackData + Copy,
+{
+    /// Add `other` to this series, matching elements by label
+    pub fn add_aligned<I>(
+        &self,
+        labels: &Series<I>,
+        other: &Series<T>,
+        other_labels: &Series<I>,
+        fill_value: Option<T>,
+    ) -> Result<(Series<I>, Series<T>), BlackJackError>
+    where
+        I: BlackJackData,
+        T: Add<Output = T>,
+    {
+        self.combine_aligned(labels, other, other_labels, fill_value, |a, b| a + b)
+    }
+
+    /// Subtract `other` from this series, matching elements by label
+    pub fn sub_aligned<I>(
+        &self,
+        labels: &Series<I>,
+        other: &Series<T>,
+        other_labels: &Series<I>,
+        fill_value: Option<T>,
+    ) -> Result<(Series<I>, Series<T>), BlackJackError>
+    where
+        I: BlackJackData,
+        T: Sub<Output = T>,
+    {
+        self.combine_aligned(labels, other, other_labels, fill_value, |a, b| a - b)
+    }
+
+    /// Multiply this series by `other`, matching elements by label
+    pub fn mul_aligned<I>(
+        &self,
+        labels: &Series<I>,
+        other: &Series<T>,
+        other_labels: &Series<I>,
+        fill_value: Option<T>,
+    ) -> Result<(Series<I>, Series<T>), BlackJackError>
+    where
+        I: BlackJackData,
+        T: Mul<Output = T>,
+    {
+        self.combine_aligned(labels, other, other_labels, fill_value, |a, b| a * b)
+    }
+
+    /// Divide this series by `other`, matching elements by label
+    pub fn div_aligned<I>(
+        &self,
+        labels: &Series<I>,
+        other: &Series<T>,
+        other_labels: &Series<I>,
+        fill_value: Option<T>,
+    ) -> Result<(Series<I>, Series<T>), BlackJackError>
+    where
+        I: BlackJackData,
+        T: Div<Output = T>,
+    {
+        self.combine_aligned(labels, other, other_labels, fill_value, |a, b| a / b)
+    }
+
+    /// Combine this series with `other` by `op`, matching elements by label; returning
+    /// the labels of the result alongside its values, named as this series.
+    ///
+    /// Returns a `LengthMismatch` error if either series doesn't have a label per
+    /// element, or a `ValueError` if either has duplicate labels.
+    pub fn combine_aligned<I, F>(
+        &self,
+        labels: &Series<I>,
+        other: &Series<T>,
+        other_labels: &Series<I>,
+        fill_value: Option<T>,
+        op: F,
+    ) -> Result<(Series<I>, Series<T>), BlackJackError>
+    where
+        I: BlackJackData,
+        F: Fn(T, T) -> T,
+    {
+        // Position of each label on either side
+        let mut positions: IndexMap<HashKey<I>, (Option<usize>, Option<usize>)> = IndexMap::new();
+        for (side, series, labels) in [(0, self, labels), (1, other, other_labels)].iter() {
+            if series.len() != labels.len() {
+                return Err(BlackJackError::LengthMismatch(format!(
+                    "Series is of size: {}, and its labels are of size: {}",
+                    series.len(),
+                    labels.len()
+                )));
+            }
+            for (pos, label) in labels.values.iter().enumerate() {
+                let entry = positions.entry(HashKey(label)).or_insert((None, None));
+                let slot = if *side == 0 {
+                    &mut entry.0
+                } else {
+                    &mut entry.1
+                };
+                if slot.replace(pos).is_some() {
+                    return Err(BlackJackError::ValueError(format!(
+                        "Duplicate label: {}",
+                        label
+                    )));
+                }
+            }
+        }
+
+        let (left_na, right_na) = (
+            self.isna().collect::<Vec<bool>>(),
+            other.isna().collect::<Vec<bool>>(),
+        );
+        let values = positions
+            .values()
+            .map(|(left, right)| {
+                let left = left
+                    .filter(|pos| !left_na[*pos])
+                    .map(|pos| self.values[pos]);
+                let right = right
+                    .filter(|pos| !right_na[*pos])
+                    .map(|pos| other.values[pos]);
+                match (left.or(fill_value), right.or(fill_value)) {
+                    (Some(a), Some(b)) if left.is_some() || right.is_some() => Some(op(a, b)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let mut result_labels =
+            Series::from_vec(positions.keys().map(|key| key.0.clone()).collect());
+        result_labels.name = labels.name.clone();
+        let mut result = Series::from_options(values);
+        result.name = self.name.clone();
+        Ok((result_labels, result))
+    }
+}
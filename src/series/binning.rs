@@ -0,0 +1,156 @@
+//! Binning numeric `Series` into intervals, either between given edges with `cut`
+//! or into bins holding equal numbers of values with `qcut`.
+//!
+//! Both yield a [`Categorical`] of the bin each element falls into, with a category
+//! per bin in order, alongside the edges of the bins. Missing values, and values
+//! outside of the edges, are null.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![1, 5, 7, 10]);
+//!
+//! let (binned, edges) = series.cut(&[0., 5., 10.], None, true).unwrap();
+//! assert_eq!(binned.categories(), &["(0, 5]".to_string(), "(5, 10]".to_string()]);
+//! assert_eq!(binned.codes().values, vec![0, 0, 1, 1]);
+//! assert_eq!(edges, vec![0., 5., 10.]);
+//!
+//! let (binned, edges) = series.qcut(2).unwrap();
+//! assert_eq!(binned.get(0), Some("[1, 6]"));
+//! assert_eq!(binned.get(3), Some("(6, 10]"));
+//! assert_eq!(edges, vec![1., 6., 10.]);
+//! ```
+
+use num::ToPrimitive;
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData + ToPrimitive,
+{
+    /// Bin the values between consecutive `bins` edges, which must be increasing.
+    /// Bins include their right edge if `right`, otherwise their left edge. Each bin
+    /// is labelled by `labels` if given, one per bin, otherwise by its interval.
+    ///
+    /// Returns a `ValueError` if there are fewer than two edges, the edges aren't
+    /// increasing, or the labels don't match the bins.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![0., 4.5, 5., 12.]);
+    /// let labels = vec!["low".to_string(), "high".to_string()];
+    ///
+    /// let (binned, _) = series.cut(&[0., 5., 10.], Some(labels), false).unwrap();
+    /// assert_eq!(binned.get(0), Some("low"));
+    /// assert_eq!(binned.get(2), Some("high"));
+    /// assert_eq!(binned.get(3), None);
+    ///
+    /// assert!(series.cut(&[5., 0.], None, true).is_err());
+    /// ```
+    pub fn cut(
+        &self,
+        bins: &[f64],
+        labels: Option<Vec<String>>,
+        right: bool,
+    ) -> Result<(Categorical, Vec<f64>), BlackJackError> {
+        if bins.len() < 2 {
+            return Err(BlackJackError::ValueError(format!(
+                "At least 2 bin edges are required, found {}",
+                bins.len()
+            )));
+        }
+        if !increasing(bins) {
+            return Err(BlackJackError::ValueError(
+                "Bin edges must be increasing".to_owned(),
+            ));
+        }
+        self.bin(bins.to_vec(), labels, right, false)
+    }
+
+    /// Bin the values into `q` bins of equal size, by their quantiles; each bin
+    /// includes its right edge, and the first also its left edge, the minimum.
+    ///
+    /// Returns a `ValueError` if `q` is zero, there are no values to bin, or the
+    /// quantiles aren't distinct enough to give `q` bins.
+    pub fn qcut(&self, q: usize) -> Result<(Categorical, Vec<f64>), BlackJackError> {
+        if q == 0 || self.count() == 0 {
+            return Err(BlackJackError::ValueError(format!(
+                "Cannot bin {} values into {} quantiles",
+                self.count(),
+                q
+            )));
+        }
+        let edges = (0..=q)
+            .map(|i| self.quantile(i as f64 / q as f64))
+            .collect::<Result<Vec<f64>, BlackJackError>>()?;
+        if !increasing(&edges) {
+            return Err(BlackJackError::ValueError(format!(
+                "Quantile edges {:?} are not distinct, use fewer quantiles",
+                edges
+            )));
+        }
+        self.bin(edges, None, true, true)
+    }
+
+    // Categorical of the bin between `edges` each value falls into
+    fn bin(
+        &self,
+        edges: Vec<f64>,
+        labels: Option<Vec<String>>,
+        right: bool,
+        include_lowest: bool,
+    ) -> Result<(Categorical, Vec<f64>), BlackJackError> {
+        let n_bins = edges.len() - 1;
+        let labels = match labels {
+            Some(labels) if labels.len() != n_bins => {
+                return Err(BlackJackError::ValueError(format!(
+                    "Expected {} labels for the bins, found {}",
+                    n_bins,
+                    labels.len()
+                )))
+            }
+            Some(labels) => labels,
+            None => (0..n_bins)
+                .map(|i| {
+                    let (open, close) = match (right, include_lowest && i == 0) {
+                        (true, false) => ("(", "]"),
+                        (true, true) => ("[", "]"),
+                        (false, _) => ("[", ")"),
+                    };
+                    format!("{}{}, {}{}", open, edges[i], edges[i + 1], close)
+                })
+                .collect(),
+        };
+
+        let na = self.isna().collect::<Vec<bool>>();
+        let codes = (0..self.len())
+            .map(|idx| {
+                let value = self.values[idx].to_f64().filter(|_| !na[idx])?;
+                if include_lowest && value == edges[0] {
+                    return Some(0);
+                }
+                // Number of edges before the value, or at it if bins include their left edge
+                let below = edges
+                    .iter()
+                    .filter(|edge| **edge < value || (!right && **edge == value))
+                    .count();
+                if (1..=n_bins).contains(&below) {
+                    Some(below as i32 - 1)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let mut codes = Series::from_options(codes);
+        codes.name = self.name.clone();
+        Ok((Categorical::from_codes(codes, labels)?, edges))
+    }
+}
+
+fn increasing(edges: &[f64]) -> bool {
+    edges.windows(2).all(|pair| pair[0] < pair[1])
+}
@@ -0,0 +1,120 @@
+//! Compact bit-packed storage of booleans, used to track the validity
+//! (non-missing-ness) of elements within a `Series`
+
+use std::iter::FromIterator;
+
+use serde::{Deserialize, Serialize};
+
+const WORD_BITS: usize = 64;
+
+/// Bit-packed collection of booleans, where each element takes a single bit.
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let mut bitmap = Bitmap::new(3, true);
+/// bitmap.set(1, false);
+/// bitmap.push(false);
+///
+/// assert_eq!(bitmap.len(), 4);
+/// assert_eq!(bitmap.count_zeros(), 2);
+/// assert_eq!(bitmap.iter().collect::<Vec<bool>>(), vec![true, false, true, false]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct Bitmap {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bitmap {
+    /// Create a new `Bitmap` of a given length with every bit set to `value`
+    pub fn new(len: usize, value: bool) -> Self {
+        let fill = if value { u64::MAX } else { 0 };
+        let mut bitmap = Bitmap {
+            words: vec![fill; len.div_ceil(WORD_BITS)],
+            len,
+        };
+        bitmap.clear_unused_bits();
+        bitmap
+    }
+
+    /// Number of bits held
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determine if the bitmap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the bit at position `idx`, returns `None` if out of bounds.
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        if idx < self.len {
+            Some(self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Set the bit at position `idx`; panics if `idx` is out of bounds.
+    pub fn set(&mut self, idx: usize, value: bool) {
+        assert!(
+            idx < self.len,
+            "Index {} out of bounds for bitmap of length {}",
+            idx,
+            self.len
+        );
+        let word = &mut self.words[idx / WORD_BITS];
+        if value {
+            *word |= 1 << (idx % WORD_BITS);
+        } else {
+            *word &= !(1 << (idx % WORD_BITS));
+        }
+    }
+
+    /// Append a bit to the end of the bitmap
+    pub fn push(&mut self, value: bool) {
+        if self.words.len() * WORD_BITS == self.len {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Count of bits set to `true`
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Count of bits set to `false`
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Iterate over the bits as booleans
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |idx| self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0)
+    }
+
+    // Keep bits beyond `len` zeroed so equality only considers the bits in use.
+    fn clear_unused_bits(&mut self) {
+        let used = self.len % WORD_BITS;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+}
+
+impl FromIterator<bool> for Bitmap {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bitmap = Bitmap::default();
+        for value in iter {
+            bitmap.push(value);
+        }
+        bitmap
+    }
+}
@@ -0,0 +1,307 @@
+//! Dictionary encoded string columns, storing each distinct string once
+//! alongside an integer code per element.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![
+//!     "red".to_string(),
+//!     "blue".to_string(),
+//!     "red".to_string(),
+//! ]);
+//!
+//! let categorical = series.to_categorical();
+//! assert_eq!(categorical.categories(), &["red".to_string(), "blue".to_string()]);
+//! assert_eq!(categorical.codes().values, vec![0, 1, 0]);
+//!
+//! // Convert back to a series of strings
+//! assert_eq!(categorical.to_series(), series);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::hashing::{counts_series, proportions_series};
+use crate::prelude::*;
+
+// Placeholder referenced by null elements, as with `T::default()` in a `Series`
+static EMPTY: String = String::new();
+
+/// A column of strings stored as `i32` codes into a dictionary of distinct categories.
+///
+/// Null elements are tracked by the validity of the codes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Categorical {
+    codes: Series<i32>,
+    categories: Vec<String>,
+}
+
+impl Categorical {
+    /// Create a `Categorical` from codes indexing into `categories`; each valid code
+    /// must be within bounds and each category distinct. The name is taken from `codes`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let categories = vec!["low".to_string(), "high".to_string()];
+    /// let categorical = Categorical::from_codes(Series::from_vec(vec![1, 0, 1]), categories).unwrap();
+    /// assert_eq!(categorical.get(0), Some("high"));
+    ///
+    /// let categories = vec!["low".to_string()];
+    /// assert!(Categorical::from_codes(Series::from_vec(vec![1]), categories).is_err());
+    /// ```
+    pub fn from_codes(codes: Series<i32>, categories: Vec<String>) -> Result<Self, BlackJackError> {
+        let distinct = categories.iter().collect::<HashSet<&String>>();
+        if distinct.len() != categories.len() {
+            return Err(BlackJackError::ValueError(
+                "Categories must be distinct".to_owned(),
+            ));
+        }
+        for idx in 0..codes.len() {
+            if let Some(code) = codes.get(idx) {
+                if *code < 0 || *code as usize >= categories.len() {
+                    return Err(BlackJackError::ValueError(format!(
+                        "Code {} at position {} is out of bounds for {} categories",
+                        code,
+                        idx,
+                        categories.len()
+                    )));
+                }
+            }
+        }
+        Ok(Categorical { codes, categories })
+    }
+
+    /// Number of elements, including nulls
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    /// Determine if there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Get the name of the column, if set
+    pub fn name(&self) -> Option<String> {
+        self.codes.name()
+    }
+
+    /// Set the name of the column
+    pub fn set_name(&mut self, name: &str) {
+        self.codes.set_name(name)
+    }
+
+    /// The codes of each element, indexing into [`Categorical::categories`]
+    pub fn codes(&self) -> &Series<i32> {
+        &self.codes
+    }
+
+    /// The distinct categories, ordered by first appearance when created from a `Series<String>`
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Get the category of an element, `None` if null or out of bounds
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        self.codes
+            .get(idx)
+            .map(|code| self.categories[*code as usize].as_str())
+    }
+
+    /// Count of null elements
+    pub fn null_count(&self) -> usize {
+        self.codes.null_count()
+    }
+
+    /// Decode into a `Series<String>`, keeping the name and nulls
+    pub fn to_series(&self) -> Series<String> {
+        let values = (0..self.len())
+            .map(|idx| self.get(idx).map(|category| category.to_string()))
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name();
+        series
+    }
+
+    /// Drop positions of the column
+    pub fn drop_positions<I>(&mut self, positions: I)
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.codes.drop_positions(positions)
+    }
+
+    /// Create a new column from the elements at the given positions, in that order;
+    /// see [`Series::take`].
+    pub fn take(&self, positions: &[usize]) -> Categorical {
+        Categorical {
+            codes: self.codes.take(positions),
+            categories: self.categories.clone(),
+        }
+    }
+
+    /// Shift the elements by a number of periods; see [`Series::shift`].
+    pub fn shift(&self, periods: i64) -> Categorical {
+        Categorical {
+            codes: self.codes.shift(periods),
+            categories: self.categories.clone(),
+        }
+    }
+
+    /// Replace null elements with `value`, appending it to the categories if it isn't one
+    pub fn fillna(&self, value: &str) -> Categorical {
+        let mut categories = self.categories.clone();
+        let code = match categories.iter().position(|category| category == value) {
+            Some(code) => code,
+            None => {
+                categories.push(value.to_string());
+                categories.len() - 1
+            }
+        };
+        Categorical {
+            codes: self.codes.fillna(code as i32),
+            categories,
+        }
+    }
+
+    /// Group a series by the categories of this column, comparing codes rather than
+    /// strings. Groups are named by category in order of first appearance, and elements
+    /// with a null category are dropped.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let keys = Series::from_vec(vec!["a".to_string(), "b".to_string(), "a".to_string()]);
+    /// let values = Series::from_vec(vec![1, 2, 3]);
+    ///
+    /// let grouped = keys.to_categorical().groupby(&values);
+    /// assert_eq!(grouped.sum().values, vec![4, 2]);
+    /// ```
+    pub fn groupby<T>(&self, values: &Series<T>) -> SeriesGroupBy<T>
+    where
+        T: BlackJackData,
+    {
+        // Position of each code's group within `groups`, once seen
+        let mut slots: Vec<Option<usize>> = vec![None; self.categories.len()];
+        let mut groups: Vec<(i32, Vec<Option<T>>, Vec<usize>)> = vec![];
+
+        for idx in 0..self.len().min(values.len()) {
+            if let Some(code) = self.codes.get(idx) {
+                let slot = *slots[*code as usize].get_or_insert_with(|| {
+                    groups.push((*code, vec![], vec![]));
+                    groups.len() - 1
+                });
+                groups[slot].1.push(values.get(idx).cloned());
+                groups[slot].2.push(idx);
+            }
+        }
+
+        let (groups, positions) = groups
+            .into_iter()
+            .map(|(code, values, positions)| {
+                let mut series = Series::from_options(values);
+                series.set_name(&self.categories[code as usize]);
+                (series, positions)
+            })
+            .unzip();
+
+        SeriesGroupBy::from_positions(groups, positions, values.len())
+    }
+
+    /// Count the occurrences of each category, including those which don't occur; see
+    /// [`Series::value_counts`]. Unsorted counts are in the order of the categories.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["a".to_string(), "b".to_string(), "b".to_string()]);
+    /// let (categories, counts) = series.to_categorical().value_counts(true, true);
+    ///
+    /// assert_eq!(categories.values, vec!["b".to_string(), "a".to_string()]);
+    /// assert_eq!(counts.values, vec![2, 1]);
+    /// ```
+    pub fn value_counts(&self, sort: bool, dropna: bool) -> (Series<String>, Series<i64>) {
+        let (categories, counts) = self.count_categories(sort, dropna);
+        let counts = counts_series(&counts, &self.name());
+        (categories, counts)
+    }
+
+    /// As [`Categorical::value_counts`], giving the proportion of the values counted
+    /// rather than the count
+    pub fn value_counts_normalized(
+        &self,
+        sort: bool,
+        dropna: bool,
+    ) -> (Series<String>, Series<f64>) {
+        let (categories, counts) = self.count_categories(sort, dropna);
+        let proportions = proportions_series(&counts, &self.name());
+        (categories, proportions)
+    }
+
+    // Categories, with null as missing, and the number of occurrences of each
+    fn count_categories(&self, sort: bool, dropna: bool) -> (Series<String>, Vec<usize>) {
+        // Count by code, with any nulls counted after the categories
+        let mut counts = vec![0_usize; self.categories.len() + 1];
+        for idx in 0..self.len() {
+            match self.codes.get(idx) {
+                Some(code) => counts[*code as usize] += 1,
+                None => counts[self.categories.len()] += 1,
+            }
+        }
+        if dropna || counts[self.categories.len()] == 0 {
+            counts.pop();
+        }
+
+        let mut order = (0..counts.len()).collect::<Vec<usize>>();
+        if sort {
+            order.sort_by(|a, b| counts[*b].cmp(&counts[*a]));
+        }
+
+        let mut categories = Series::from_options(
+            order
+                .iter()
+                .map(|code| self.categories.get(*code).cloned())
+                .collect(),
+        );
+        categories.name = self.name();
+        (categories, order.iter().map(|code| counts[*code]).collect())
+    }
+
+    // Category of an element, or an empty string if it's null.
+    pub(crate) fn category_or_default(&self, idx: usize) -> &String {
+        self.codes
+            .get(idx)
+            .map(|code| &self.categories[*code as usize])
+            .unwrap_or(&EMPTY)
+    }
+}
+
+impl Series<String> {
+    /// Dictionary encode the series into a [`Categorical`], with categories in
+    /// order of first appearance; nulls and the name are kept.
+    pub fn to_categorical(&self) -> Categorical {
+        let mut lookup: HashMap<&str, i32> = HashMap::new();
+        let mut categories = vec![];
+
+        let codes = (0..self.len())
+            .map(|idx| {
+                self.get(idx).map(|value| {
+                    *lookup.entry(value.as_str()).or_insert_with(|| {
+                        categories.push(value.clone());
+                        categories.len() as i32 - 1
+                    })
+                })
+            })
+            .collect();
+
+        let mut codes = Series::from_options(codes);
+        codes.name = self.name.clone();
+        Categorical { codes, categories }
+    }
+}
@@ -0,0 +1,153 @@
+//! Vectorized comparisons for `Series`, yielding boolean masks
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![1, 2, 3, 4]);
+//! let other = Series::from_vec(vec![4, 3, 2, 1]);
+//!
+//! // Compare against a scalar...
+//! let mask = series.gt(2).unwrap();
+//! assert_eq!(mask.values, vec![false, false, true, true]);
+//!
+//! // ...or element-wise against another series, and combine masks.
+//! let mask = (mask & series.eq(&other).unwrap()).unwrap();
+//! assert_eq!(mask.values, vec![false, false, false, false]);
+//! ```
+
+use crate::prelude::*;
+
+/// The right hand side of a comparison; either a scalar or another [`Series`]
+pub enum Operand<'a, T>
+where
+    T: BlackJackData,
+{
+    /// Compare each element against a single value
+    Scalar(T),
+
+    /// Compare element-wise against another series of the same length
+    Series(&'a Series<T>),
+}
+
+impl<'a, T> From<T> for Operand<'a, T>
+where
+    T: BlackJackData,
+{
+    fn from(scalar: T) -> Operand<'a, T> {
+        Operand::Scalar(scalar)
+    }
+}
+
+impl<'a, T> From<&'a Series<T>> for Operand<'a, T>
+where
+    T: BlackJackData,
+{
+    fn from(series: &'a Series<T>) -> Operand<'a, T> {
+        Operand::Series(series)
+    }
+}
+
+/// Comparison methods; each yields a `Series<bool>` mask which is null wherever
+/// either side of the comparison is null. Comparing against a series of a
+/// different length is an error.
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// Element-wise `>`
+    pub fn gt<'a, O>(&self, other: O) -> Result<Series<bool>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: PartialOrd + 'a,
+    {
+        self.compare(other.into(), |a, b| a > b)
+    }
+
+    /// Element-wise `>=`
+    pub fn ge<'a, O>(&self, other: O) -> Result<Series<bool>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: PartialOrd + 'a,
+    {
+        self.compare(other.into(), |a, b| a >= b)
+    }
+
+    /// Element-wise `<`
+    pub fn lt<'a, O>(&self, other: O) -> Result<Series<bool>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: PartialOrd + 'a,
+    {
+        self.compare(other.into(), |a, b| a < b)
+    }
+
+    /// Element-wise `<=`
+    pub fn le<'a, O>(&self, other: O) -> Result<Series<bool>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: PartialOrd + 'a,
+    {
+        self.compare(other.into(), |a, b| a <= b)
+    }
+
+    /// Element-wise `==`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["a".to_string(), "b".to_string()]);
+    /// let mask = series.eq("a".to_string()).unwrap();
+    /// assert_eq!(mask.values, vec![true, false]);
+    /// ```
+    pub fn eq<'a, O>(&self, other: O) -> Result<Series<bool>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: PartialEq + 'a,
+    {
+        self.compare(other.into(), |a, b| a == b)
+    }
+
+    /// Element-wise `!=`
+    pub fn ne<'a, O>(&self, other: O) -> Result<Series<bool>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: PartialEq + 'a,
+    {
+        self.compare(other.into(), |a, b| a != b)
+    }
+
+    fn compare<F>(&self, other: Operand<'_, T>, op: F) -> Result<Series<bool>, BlackJackError>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let (values, validity) = match other {
+            Operand::Scalar(scalar) => {
+                let values = self.values.iter().map(|v| op(v, &scalar)).collect();
+                (values, self.validity.clone())
+            }
+            Operand::Series(other) => {
+                if self.len() != other.len() {
+                    return Err(BlackJackError::LengthMismatch(format!(
+                        "Source series is of size: {}, and other is of size: {}",
+                        self.len(),
+                        other.len()
+                    )));
+                }
+                let values = self
+                    .values
+                    .iter()
+                    .zip(other.values.iter())
+                    .map(|(a, b)| op(a, b))
+                    .collect();
+                (values, self.combined_validity(other))
+            }
+        };
+        let mut mask = Series::from_vec(values);
+        mask.name = self.name.clone();
+        mask.dtype = Some(DType::BOOL);
+        mask.validity = validity;
+        Ok(mask)
+    }
+}
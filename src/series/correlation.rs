@@ -0,0 +1,149 @@
+//! Covariance and correlation between two numeric `Series`, see [`DataFrame::cov`]
+//! and [`DataFrame::corr`] for the matrices over every numeric column of a dataframe.
+//!
+//! Positions where either series is missing, null or `NaN`, are excluded, and at
+//! least two remaining pairs are required.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let x = Series::from_vec(vec![1, 2, 3, 4]);
+//! let y = Series::from_options(vec![Some(2.), Some(4.), Some(7.), None]);
+//!
+//! assert_eq!(x.cov(&y).unwrap(), 2.5);
+//! assert!(x.corr(&y, CorrelationMethod::Pearson).unwrap() > 0.99);
+//! assert_eq!(x.corr(&y, CorrelationMethod::Spearman).unwrap(), 1.);
+//! assert_eq!(x.corr(&y, CorrelationMethod::Kendall).unwrap(), 1.);
+//! ```
+
+use num::ToPrimitive;
+
+use crate::funcs;
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData + ToPrimitive,
+{
+    /// Sample covariance with `other`, normalized by the number of pairs minus one
+    ///
+    /// Returns a `LengthMismatch` error if the lengths differ, or a `ValueError` if
+    /// there are fewer than two pairs without missing values.
+    pub fn cov<O>(&self, other: &Series<O>) -> Result<f64, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        let (x, y) = self.complete_pairs(other)?;
+        Ok(covariance(&x, &y))
+    }
+
+    /// Correlation with `other` by `method`, between -1 and 1; `NaN` if either
+    /// series is constant over the pairs compared.
+    ///
+    /// Returns a `LengthMismatch` error if the lengths differ, or a `ValueError` if
+    /// there are fewer than two pairs without missing values.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let x = Series::from_vec(vec![1., 2., 3., 4., 5.]);
+    /// let y = Series::from_vec(vec![5., 6., 7., 8., 7.]);
+    ///
+    /// assert_eq!(x.corr(&y, CorrelationMethod::Pearson).unwrap(), 0.8320502943378437);
+    /// assert_eq!(x.corr(&y, CorrelationMethod::Spearman).unwrap(), 0.8207826816681233);
+    /// assert_eq!(x.corr(&y, CorrelationMethod::Kendall).unwrap(), 0.7378647873726218);
+    /// ```
+    pub fn corr<O>(
+        &self,
+        other: &Series<O>,
+        method: CorrelationMethod,
+    ) -> Result<f64, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        let (x, y) = self.complete_pairs(other)?;
+        let corr = match method {
+            CorrelationMethod::Pearson => pearson(&x, &y),
+            CorrelationMethod::Spearman => {
+                let rank = |values: Vec<f64>| {
+                    Series::from_vec(values)
+                        .rank(RankMethod::Average, false)
+                        .values
+                };
+                pearson(&rank(x), &rank(y))
+            }
+            CorrelationMethod::Kendall => kendall(&x, &y),
+        };
+        Ok(corr)
+    }
+
+    // Values of both series as `f64`, at the positions where neither is missing
+    fn complete_pairs<O>(&self, other: &Series<O>) -> Result<(Vec<f64>, Vec<f64>), BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        if self.len() != other.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Source series is of size: {}, and other is of size: {}",
+                self.len(),
+                other.len()
+            )));
+        }
+        let pairs: (Vec<f64>, Vec<f64>) = self
+            .isna()
+            .zip(other.isna())
+            .enumerate()
+            .filter(|(_, (left_na, right_na))| !left_na && !right_na)
+            .filter_map(|(idx, _)| Some((self.values[idx].to_f64()?, other.values[idx].to_f64()?)))
+            .unzip();
+        if pairs.0.len() < 2 {
+            return Err(BlackJackError::ValueError(format!(
+                "At least 2 pairs of values are required, found {}",
+                pairs.0.len()
+            )));
+        }
+        Ok(pairs)
+    }
+}
+
+fn covariance(x: &[f64], y: &[f64]) -> f64 {
+    let mean = |values: &[f64]| funcs::mean(values).unwrap_or(f64::NAN);
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    let sum = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - x_mean) * (b - y_mean))
+        .sum::<f64>();
+    sum / (x.len() - 1) as f64
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    covariance(x, y) / (covariance(x, x) * covariance(y, y)).sqrt()
+}
+
+// Kendall's tau-b, comparing every pair of positions
+fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    let sign = |diff: f64| {
+        if diff > 0. {
+            1
+        } else if diff < 0. {
+            -1
+        } else {
+            0
+        }
+    };
+    // Concordant minus discordant pairs, and pairs untied in each of x and y
+    let (mut score, mut untied_x, mut untied_y) = (0_i64, 0_u64, 0_u64);
+    for i in 0..x.len() {
+        for j in i + 1..x.len() {
+            let (dx, dy) = (sign(x[i] - x[j]), sign(y[i] - y[j]));
+            score += dx * dy;
+            untied_x += dx.unsigned_abs();
+            untied_y += dy.unsigned_abs();
+        }
+    }
+    // Each count is up to n² / 2, so their product may overflow as an integer
+    score as f64 / (untied_x as f64 * untied_y as f64).sqrt()
+}
@@ -0,0 +1,113 @@
+//! Cumulative operations on `Series`, yielding a running aggregate of the same length.
+//!
+//! Missing values, nulls and `NaN`, are skipped and remain null in the result.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![1., f64::NAN, 2., 3.]);
+//!
+//! let running = series.cumsum();
+//! assert_eq!(running.get(1), None);
+//! assert_eq!(running.get(3), Some(&6.));
+//!
+//! assert_eq!(series.cummax().get(2), Some(&2.));
+//! ```
+
+use std::ops::{Add, Mul};
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// Running sum of the series
+    pub fn cumsum(&self) -> Series<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.accumulate(|acc, value| acc + value)
+    }
+
+    /// Running product of the series
+    pub fn cumprod(&self) -> Series<T>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        self.accumulate(|acc, value| acc * value)
+    }
+
+    /// Running minimum of the series
+    pub fn cummin(&self) -> Series<T>
+    where
+        T: PartialOrd,
+    {
+        self.accumulate(|acc, value| if value < acc { value } else { acc })
+    }
+
+    /// Running maximum of the series
+    pub fn cummax(&self) -> Series<T>
+    where
+        T: PartialOrd,
+    {
+        self.accumulate(|acc, value| if value > acc { value } else { acc })
+    }
+
+    /// Number each non-missing element, counting from zero
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![Some("a".to_string()), None, Some("b".to_string())]);
+    /// let counts = series.cumcount();
+    ///
+    /// assert_eq!((counts.get(0), counts.get(1), counts.get(2)), (Some(&0), None, Some(&1)));
+    /// ```
+    pub fn cumcount(&self) -> Series<i64> {
+        let mut count = -1;
+        let values = self
+            .isna()
+            .map(|na| {
+                if na {
+                    None
+                } else {
+                    count += 1;
+                    Some(count)
+                }
+            })
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series
+    }
+
+    // Fold the non-missing values into a running aggregate, keeping the name
+    fn accumulate<F>(&self, op: F) -> Series<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let mut acc: Option<T> = None;
+        let values = self
+            .values
+            .iter()
+            .zip(self.isna())
+            .map(|(value, na)| {
+                if na {
+                    return None;
+                }
+                let next = match acc.take() {
+                    Some(acc) => op(acc, value.clone()),
+                    None => value.clone(),
+                };
+                acc = Some(next.clone());
+                Some(next)
+            })
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series
+    }
+}
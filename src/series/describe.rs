@@ -0,0 +1,112 @@
+//! Summary statistics of a `Series` in a single call, see [`DataFrame::describe`]
+//! for the equivalent over every column of a dataframe.
+//!
+//! Each `describe` returns the names of the statistics alongside their values,
+//! which are null where a statistic is undefined, such as the mean of an empty
+//! series or the standard deviation of a single value.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![1_i32, 2, 3, 4]);
+//! let (stats, values) = series.describe();
+//!
+//! assert_eq!(stats.values, vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]);
+//! assert_eq!(values.values[..2], [4., 2.5]);
+//! assert_eq!(values.values[3..], [1., 1.75, 2.5, 3.25, 4.]);
+//!
+//! let series = Series::from_vec(vec!["a".to_string(), "b".to_string(), "b".to_string()]);
+//! let (stats, values) = series.describe();
+//!
+//! assert_eq!(stats.values, vec!["count", "unique", "top", "freq"]);
+//! assert_eq!(values.values, vec!["3", "2", "b", "2"]);
+//! ```
+
+use num::ToPrimitive;
+
+use crate::prelude::*;
+
+// Every statistic, in the order they appear when describing a mix of dtypes
+pub(crate) const DESCRIBE_STATS: [&str; 11] = [
+    "count", "unique", "top", "freq", "mean", "std", "min", "25%", "50%", "75%", "max",
+];
+
+impl_describe_numeric!(f64);
+impl_describe_numeric!(i64);
+impl_describe_numeric!(f32);
+impl_describe_numeric!(i32);
+impl_describe_numeric!(i16);
+impl_describe_numeric!(i8);
+impl_describe_numeric!(u64);
+impl_describe_numeric!(u32);
+impl_describe_numeric!(u16);
+impl_describe_numeric!(u8);
+
+impl Series<String> {
+    /// Summarise the strings: the count of present values, the number of distinct
+    /// values, the most frequent value, first to appear on ties, and its frequency
+    pub fn describe(&self) -> (Series<String>, Series<String>) {
+        let (values, counts) = self.value_counts(true, true);
+        let stats = vec![
+            Some(self.count().to_string()),
+            Some(values.len().to_string()),
+            values.get(0).cloned(),
+            counts.get(0).map(|count| count.to_string()),
+        ];
+        (stat_labels(&DESCRIBE_STATS[..4]), self.named(stats))
+    }
+}
+
+impl<T> Series<T>
+where
+    T: BlackJackData + ToPrimitive + PartialOrd + Copy,
+{
+    // Summary of a numeric series; see `impl_describe_numeric!`
+    pub(crate) fn describe_numeric(&self) -> (Series<String>, Series<f64>) {
+        let count = self.count();
+        let stats = if count == 0 {
+            vec![None; 7]
+        } else {
+            let std = if count > 1 {
+                let values = self
+                    .non_missing()
+                    .iter()
+                    .map(|v| v.to_f64().unwrap())
+                    .collect();
+                Series::from_vec(values).std(1.).ok()
+            } else {
+                None
+            };
+            vec![
+                self.mean().ok(),
+                std,
+                self.min().ok().and_then(|min| min.to_f64()),
+                self.quantile(0.25).ok(),
+                self.quantile(0.5).ok(),
+                self.quantile(0.75).ok(),
+                self.max().ok().and_then(|max| max.to_f64()),
+            ]
+        };
+        let stats = vec![Some(count as f64)].into_iter().chain(stats).collect();
+        let labels = DESCRIBE_STATS[..1]
+            .iter()
+            .chain(&DESCRIBE_STATS[4..])
+            .cloned()
+            .collect::<Vec<&str>>();
+        (stat_labels(&labels), self.named(stats))
+    }
+}
+
+impl<T: BlackJackData> Series<T> {
+    // Series of `values` with the name of this series
+    fn named<O: BlackJackData>(&self, values: Vec<Option<O>>) -> Series<O> {
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series
+    }
+}
+
+fn stat_labels(labels: &[&str]) -> Series<String> {
+    Series::from_vec(labels.iter().map(|label| label.to_string()).collect())
+}
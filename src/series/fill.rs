@@ -0,0 +1,176 @@
+//! Filling missing values of a `Series`, either with a constant, by carrying the
+//! neighbouring values forwards or backwards, or by interpolating between them.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_options(vec![Some(1.), None, None, Some(4.)]);
+//!
+//! assert_eq!(series.fillna(0.).values, vec![1., 0., 0., 4.]);
+//! assert_eq!(series.ffill(None).values, vec![1., 1., 1., 4.]);
+//! assert_eq!(series.bfill(Some(1)).get(1), None);
+//!
+//! let interpolated = series.interpolate(InterpolationMethod::Linear).unwrap();
+//! assert_eq!(interpolated.values, vec![1., 2., 3., 4.]);
+//! ```
+
+use num::ToPrimitive;
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// Replace missing values, nulls and `NaN`, with `value`; keeping the name and dtype
+    pub fn fillna(&self, value: T) -> Series<T> {
+        let values = self
+            .values
+            .iter()
+            .zip(self.isna())
+            .map(|(current, na)| Some(if na { value.clone() } else { current.clone() }))
+            .collect();
+        self.with_options(values)
+    }
+
+    /// Replace missing values with the last value before them. With a `limit`, at
+    /// most that many consecutive missing values are filled.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![None, Some(1), None, None]);
+    /// let filled = series.ffill(Some(1));
+    ///
+    /// assert_eq!(filled.get(0), None);
+    /// assert_eq!(filled.get(1), Some(&1));
+    /// assert_eq!(filled.get(2), Some(&1));
+    /// assert_eq!(filled.get(3), None);
+    /// ```
+    pub fn ffill(&self, limit: Option<usize>) -> Series<T> {
+        self.fill_along(0..self.len(), limit)
+    }
+
+    /// Replace missing values with the next value after them. With a `limit`, at
+    /// most that many consecutive missing values are filled.
+    pub fn bfill(&self, limit: Option<usize>) -> Series<T> {
+        self.fill_along((0..self.len()).rev(), limit)
+    }
+
+    /// Estimate missing values between the first and last present values from the
+    /// values around them, using their positions as the x axis. Missing values at
+    /// either end are left null.
+    ///
+    /// Returns a `ValueError` for [`InterpolationMethod::CubicSpline`] when there are
+    /// fewer than three values to fit.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![None, Some(0), None, Some(8), None, None, Some(2)]);
+    ///
+    /// let nearest = series.interpolate(InterpolationMethod::Nearest).unwrap();
+    /// assert_eq!(nearest.get(0), None);
+    /// assert_eq!(nearest.values[1..], [0., 0., 8., 8., 2., 2.]);
+    ///
+    /// let spline = series.interpolate(InterpolationMethod::CubicSpline).unwrap();
+    /// assert_eq!(spline.get(3), Some(&8.));
+    /// assert!(spline[2] > 4.);
+    ///
+    /// assert!(Series::from_vec(vec![1, 2]).interpolate(InterpolationMethod::CubicSpline).is_err());
+    /// ```
+    pub fn interpolate(&self, method: InterpolationMethod) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        use rgsl::{InterpAccel, InterpType, Spline};
+
+        let na = self.isna().collect::<Vec<bool>>();
+        let (x, y): (Vec<f64>, Vec<f64>) = (0..self.len())
+            .filter(|idx| !na[*idx])
+            .filter_map(|idx| self.values[idx].to_f64().map(|value| (idx as f64, value)))
+            .unzip();
+
+        let spline = match method {
+            InterpolationMethod::CubicSpline => {
+                if x.len() < 3 {
+                    return Err(BlackJackError::ValueError(format!(
+                        "Cubic spline interpolation requires at least 3 values, found {}",
+                        x.len()
+                    )));
+                }
+                let spline = Spline::new(&InterpType::cspline(), x.len())
+                    .ok_or("Unable to allocate cubic spline")?;
+                spline.init(&x, &y);
+                Some(spline)
+            }
+            _ => None,
+        };
+        let mut accel = InterpAccel::new();
+
+        let values = (0..self.len())
+            .map(|idx| {
+                if !na[idx] {
+                    return self.values[idx].to_f64();
+                }
+                // Present values either side, if any
+                let pos = idx as f64;
+                let right = x.partition_point(|known| *known <= pos);
+                if right == x.len() {
+                    return None;
+                }
+                let left = right.checked_sub(1)?;
+                let value = match (method, &spline) {
+                    (InterpolationMethod::Linear, _) => {
+                        y[left] + (y[right] - y[left]) * (pos - x[left]) / (x[right] - x[left])
+                    }
+                    (InterpolationMethod::Nearest, _) => {
+                        if pos - x[left] <= x[right] - pos {
+                            y[left]
+                        } else {
+                            y[right]
+                        }
+                    }
+                    (InterpolationMethod::CubicSpline, Some(spline)) => {
+                        spline.eval(pos, &mut accel)
+                    }
+                    (InterpolationMethod::CubicSpline, None) => unreachable!(),
+                };
+                Some(value)
+            })
+            .collect();
+
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series.dtype = Some(DType::F64);
+        Ok(series)
+    }
+
+    // Fill missing values with the last present value seen walking `positions`
+    fn fill_along<P>(&self, positions: P, limit: Option<usize>) -> Series<T>
+    where
+        P: Iterator<Item = usize>,
+    {
+        let na = self.isna().collect::<Vec<bool>>();
+        let mut values = self.to_options();
+        let (mut last, mut run) = (None, 0);
+
+        for idx in positions {
+            if !na[idx] {
+                last = Some(idx);
+                run = 0;
+                continue;
+            }
+            run += 1;
+            if let Some(source) = last {
+                if run <= limit.unwrap_or(usize::MAX) {
+                    values[idx] = Some(self.values[source].clone());
+                }
+            }
+        }
+        self.with_options(values)
+    }
+}
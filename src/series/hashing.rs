@@ -0,0 +1,192 @@
+//! Hash based operations on `Series`, which work for every dtype and treat all
+//! missing values, nulls and `NaN`, as one value.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use indexmap::IndexMap;
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// Count the occurrences of each distinct value, returning the values alongside their
+    /// counts, both named as this series; see [`Series::value_counts_normalized`] for
+    /// the proportions instead.
+    ///
+    /// - `sort`: order by descending count, rather than by first appearance.
+    /// - `dropna`: don't count missing values; otherwise they're counted under a null value.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![
+    ///     Some("a".to_string()),
+    ///     Some("b".to_string()),
+    ///     None,
+    ///     Some("b".to_string()),
+    /// ]);
+    ///
+    /// let (values, counts) = series.value_counts(true, true);
+    /// assert_eq!(values.values, vec!["b".to_string(), "a".to_string()]);
+    /// assert_eq!(counts.values, vec![2, 1]);
+    ///
+    /// let (values, counts) = series.value_counts_normalized(false, false);
+    /// assert_eq!(values.get(2), None);
+    /// assert_eq!(counts.values, vec![0.25, 0.5, 0.25]);
+    /// ```
+    pub fn value_counts(&self, sort: bool, dropna: bool) -> (Series<T>, Series<i64>) {
+        let (values, counts) = self.count_values(sort, dropna);
+        let counts = counts_series(&counts, &self.name);
+        (values, counts)
+    }
+
+    /// As [`Series::value_counts`], giving the proportion of the values counted rather
+    /// than the count
+    pub fn value_counts_normalized(&self, sort: bool, dropna: bool) -> (Series<T>, Series<f64>) {
+        let (values, counts) = self.count_values(sort, dropna);
+        let proportions = proportions_series(&counts, &self.name);
+        (values, proportions)
+    }
+
+    /// The distinct values in order of first appearance, keeping the name. Missing
+    /// values appear once, as their first occurrence.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![2., f64::NAN, 1., 2., f64::NAN]);
+    /// let unique = series.unique();
+    ///
+    /// assert_eq!(unique.len(), 3);
+    /// assert_eq!(unique[0], 2.);
+    /// assert!(unique[1].is_nan());
+    /// assert_eq!(unique[2], 1.);
+    /// ```
+    pub fn unique(&self) -> Series<T> {
+        self.drop_duplicates(Keep::First)
+    }
+
+    /// Number of distinct values, counting missing values as one unless `dropna`
+    pub fn nunique(&self, dropna: bool) -> usize {
+        self.count_values(false, dropna).0.len()
+    }
+
+    /// Mask of the elements which repeat a value appearing elsewhere, except for the
+    /// occurrence chosen by `keep`. Missing values count as one value.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["a".to_string(), "b".to_string(), "a".to_string()]);
+    ///
+    /// assert_eq!(series.duplicated(Keep::First).values, vec![false, false, true]);
+    /// assert_eq!(series.duplicated(Keep::Last).values, vec![true, false, false]);
+    /// assert_eq!(series.duplicated(Keep::None).values, vec![true, false, true]);
+    /// ```
+    pub fn duplicated(&self, keep: Keep) -> Series<bool> {
+        let mut mask = Series::from_vec(duplicated_keys(&self.group_ids(), keep));
+        mask.name = self.name.clone();
+        mask
+    }
+
+    /// The series without the elements marked by [`Series::duplicated`], keeping the
+    /// order of the rest
+    pub fn drop_duplicates(&self, keep: Keep) -> Series<T> {
+        let positions = self
+            .duplicated(keep)
+            .values
+            .iter()
+            .enumerate()
+            .filter(|(_, duplicate)| !**duplicate)
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        self.take(&positions)
+    }
+
+    // Distinct values, with null as missing, and the number of occurrences of each
+    fn count_values(&self, sort: bool, dropna: bool) -> (Series<T>, Vec<usize>) {
+        // Position of the first occurrence and count of each value, with `None` as missing
+        let mut occurrences: IndexMap<Option<HashKey<'_, T>>, (usize, usize)> = IndexMap::new();
+        for (idx, (value, na)) in self.values.iter().zip(self.isna()).enumerate() {
+            if na && dropna {
+                continue;
+            }
+            let key = if na { None } else { Some(HashKey(value)) };
+            occurrences.entry(key).or_insert((idx, 0)).1 += 1;
+        }
+
+        let mut groups = occurrences
+            .into_iter()
+            .map(|(key, (idx, count))| (key.is_none(), idx, count))
+            .collect::<Vec<(bool, usize, usize)>>();
+        if sort {
+            groups.sort_by_key(|g| Reverse(g.2));
+        }
+
+        let mut values = self.take(&groups.iter().map(|g| g.1).collect::<Vec<usize>>());
+        if let Some(missing) = groups.iter().position(|g| g.0) {
+            values.set_null(missing);
+        }
+        (values, groups.iter().map(|g| g.2).collect())
+    }
+
+    // Identifier of each element's value, numbered in order of first appearance;
+    // missing values all share one
+    pub(crate) fn group_ids(&self) -> Vec<usize> {
+        let mut ids: HashMap<Option<HashKey<'_, T>>, usize> = HashMap::new();
+        self.values
+            .iter()
+            .zip(self.isna())
+            .map(|(value, na)| {
+                let key = if na { None } else { Some(HashKey(value)) };
+                let next = ids.len();
+                *ids.entry(key).or_insert(next)
+            })
+            .collect()
+    }
+}
+
+// Whether each key repeats another, except for the occurrence chosen by `keep`
+pub(crate) fn duplicated_keys<K: Hash + Eq>(keys: &[K], keep: Keep) -> Vec<bool> {
+    // Position of the occurrence to keep, and number of occurrences, of each key
+    let mut occurrences: HashMap<&K, (usize, usize)> = HashMap::new();
+    for (idx, key) in keys.iter().enumerate() {
+        let entry = occurrences.entry(key).or_insert((idx, 0));
+        if keep == Keep::Last {
+            entry.0 = idx;
+        }
+        entry.1 += 1;
+    }
+    keys.iter()
+        .enumerate()
+        .map(|(idx, key)| {
+            let (kept, count) = occurrences[key];
+            match keep {
+                Keep::First | Keep::Last => idx != kept,
+                Keep::None => count > 1,
+            }
+        })
+        .collect()
+}
+
+// Series of counts
+pub(crate) fn counts_series(counts: &[usize], name: &Option<String>) -> Series<i64> {
+    let mut series = Series::from_vec(counts.iter().map(|count| *count as i64).collect());
+    series.name = name.clone();
+    series
+}
+
+// Series of each count's proportion of the total
+pub(crate) fn proportions_series(counts: &[usize], name: &Option<String>) -> Series<f64> {
+    let total = counts.iter().sum::<usize>() as f64;
+    let mut series = Series::from_vec(counts.iter().map(|count| *count as f64 / total).collect());
+    series.name = name.clone();
+    series
+}
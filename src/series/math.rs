@@ -0,0 +1,178 @@
+//! Elementwise numeric functions on `Series`. Each consumes the series and updates
+//! its values in place, keeping the name, dtype and nulls.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let mut series = Series::from_vec(vec![-4., 1.5, 9.]);
+//! series.set_name("values");
+//!
+//! let series = series.abs().sqrt().round(2);
+//! assert_eq!(series.values, vec![2., 1.22, 3.]);
+//! assert_eq!(series.name(), Some("values".to_string()));
+//! ```
+
+use num::*;
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData + Num + Copy,
+{
+    /// Limit the values to `lower` and `upper`, either of which may be `None` for
+    /// no limit on that side
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 5, 10]);
+    /// assert_eq!(series.clone().clip(Some(2), Some(8)).values, vec![2, 5, 8]);
+    /// assert_eq!(series.clip(None, Some(4)).values, vec![1, 4, 4]);
+    /// ```
+    pub fn clip(self, lower: Option<T>, upper: Option<T>) -> Series<T>
+    where
+        T: PartialOrd,
+    {
+        self.apply(|value| match (lower, upper) {
+            (Some(lower), _) if value < lower => lower,
+            (_, Some(upper)) if value > upper => upper,
+            _ => value,
+        })
+    }
+
+    /// Raise the values to an integer power
+    ///
+    /// ## Panics
+    /// For integer dtypes, in debug builds, if a power overflows the dtype; it wraps
+    /// in release builds. Use [`Series::checked_mul`] and friends for explicit overflow.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(series.pow(3).values, vec![1, 8, 27]);
+    /// ```
+    pub fn pow(self, exp: u32) -> Series<T> {
+        self.apply(|value| num::pow(value, exp as usize))
+    }
+
+    /// Absolute value of each element
+    ///
+    /// ## Panics
+    /// For integer dtypes, in debug builds, if an element is the minimum of the dtype,
+    /// ie. `i32::MIN`, whose absolute value overflows; it stays negative in release builds.
+    pub fn abs(self) -> Series<T>
+    where
+        T: Signed,
+    {
+        self.apply(|value| value.abs())
+    }
+
+    /// Sign of each element; `1` if positive, `-1` if negative and `0` if zero.
+    /// Floats keep the sign of zero and `NaN` as is.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![-5, 0, 3]);
+    /// assert_eq!(series.sign().values, vec![-1, 0, 1]);
+    /// ```
+    pub fn sign(self) -> Series<T>
+    where
+        T: Signed,
+    {
+        self.apply(|value| {
+            if value.is_zero() {
+                value
+            } else {
+                value.signum()
+            }
+        })
+    }
+
+    /// Round the values to a number of decimal places, which may be negative to
+    /// round to tens, hundreds and so on. Halfway values round away from zero.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1.255, 1250.]);
+    /// assert_eq!(series.clone().round(1).values, vec![1.3, 1250.]);
+    /// assert_eq!(series.round(-2).values, vec![0., 1300.]);
+    /// ```
+    pub fn round(self, decimals: i32) -> Series<T>
+    where
+        T: Float,
+    {
+        let factor = T::from(10).unwrap().powi(decimals);
+        self.apply(|value| (value * factor).round() / factor)
+    }
+
+    /// Raise the values to a floating point power
+    pub fn powf(self, exp: T) -> Series<T>
+    where
+        T: Float,
+    {
+        self.apply(|value| value.powf(exp))
+    }
+
+    /// Square root of each element, `NaN` for negative values
+    pub fn sqrt(self) -> Series<T>
+    where
+        T: Float,
+    {
+        self.apply(|value| value.sqrt())
+    }
+
+    /// `e` raised to the power of each element
+    pub fn exp(self) -> Series<T>
+    where
+        T: Float,
+    {
+        self.apply(|value| value.exp())
+    }
+
+    /// Natural logarithm of each element, `NaN` for negative values and `-inf` for zero
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., std::f64::consts::E]);
+    /// assert_eq!(series.ln().values, vec![0., 1.]);
+    /// ```
+    pub fn ln(self) -> Series<T>
+    where
+        T: Float,
+    {
+        self.apply(|value| value.ln())
+    }
+
+    /// Base 10 logarithm of each element, `NaN` for negative values and `-inf` for zero
+    pub fn log10(self) -> Series<T>
+    where
+        T: Float,
+    {
+        self.apply(|value| value.log10())
+    }
+
+    // Replace each value with `func` of it; null slots keep their default
+    fn apply<F>(self, func: F) -> Series<T>
+    where
+        F: Fn(T) -> T,
+    {
+        let mut series = self;
+        for idx in 0..series.len() {
+            if !series.is_null(idx) {
+                series.values[idx] = func(series.values[idx]);
+            }
+        }
+        series
+    }
+}
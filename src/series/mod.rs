@@ -16,6 +16,8 @@
 //! assert_eq!(series.len(), 5);
 //! ```
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::convert::From;
 use std::fmt;
 use std::iter::{FromIterator, Sum};
@@ -31,13 +33,33 @@ use num::*;
 use rayon::prelude::*;
 use stats;
 
+pub mod aligned;
+pub mod binning;
+pub mod bitmap;
+pub mod categorical;
+pub mod comparisons;
+pub mod correlation;
+pub mod cumulative;
+pub mod describe;
+pub mod fill;
+pub mod hashing;
+pub mod math;
+pub mod moments;
+pub mod overflow;
 pub mod overloaders;
+pub mod periods;
 pub mod rolling;
+pub mod selection;
 pub mod series_groupby;
+pub mod sorting;
 pub mod variants;
 
+pub use self::bitmap::*;
+pub use self::categorical::*;
+pub use self::comparisons::*;
 pub use self::rolling::*;
 pub use self::series_groupby::*;
+pub use self::sorting::*;
 pub use self::variants::*;
 
 use crate::funcs;
@@ -49,9 +71,18 @@ impl_series_into_iter!(f64);
 impl_series_into_iter!(i64);
 impl_series_into_iter!(f32);
 impl_series_into_iter!(i32);
+impl_series_into_iter!(i16);
+impl_series_into_iter!(i8);
+impl_series_into_iter!(u64);
+impl_series_into_iter!(u32);
+impl_series_into_iter!(u16);
+impl_series_into_iter!(u8);
+impl_series_into_iter!(bool);
+impl_series_into_iter!(DateTime);
+impl_series_into_iter!(Duration);
 
 /// Series struct for containing underlying Array and other meta data.
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, PartialOrd)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Series<T>
 where
     T: BlackJackData,
@@ -64,6 +95,10 @@ where
     pub values: Vec<T>,
 
     dtype: Option<DType>,
+
+    /// Validity of each element, where `None` indicates no elements are missing.
+    #[serde(default)]
+    validity: Option<Bitmap>,
 }
 
 impl<I> Default for Series<I>
@@ -100,6 +135,7 @@ where
             name: None,
             dtype,
             values,
+            validity: None,
         }
     }
 
@@ -125,6 +161,16 @@ where
                 }
             })
             .collect::<Vec<T>>();
+
+        if let Some(ref validity) = self.validity {
+            let validity = validity
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| !positions.contains(position))
+                .map(|(_, valid)| valid)
+                .collect();
+            self.validity = compact_validity(validity);
+        }
     }
 
     /// Fetch values from the series by matching index _positions_, _not_ by index value.
@@ -153,6 +199,37 @@ where
             .collect::<Vec<&T>>()
     }
 
+    /// Create a new series from the elements at the given _positions_, in that order,
+    /// keeping the name, dtype and nulls. Panics if a position is out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![10, 20, 30]);
+    /// assert_eq!(series.take(&[2, 0, 0]).values, vec![30, 10, 10]);
+    /// ```
+    pub fn take(&self, positions: &[usize]) -> Series<T> {
+        let values = positions
+            .iter()
+            .map(|idx| self.values[*idx].clone())
+            .collect();
+        let validity = self.validity.as_ref().and_then(|validity| {
+            compact_validity(
+                positions
+                    .iter()
+                    .map(|idx| validity.get(*idx).unwrap())
+                    .collect(),
+            )
+        });
+        Series {
+            name: self.name.clone(),
+            dtype: self.dtype.clone(),
+            values,
+            validity,
+        }
+    }
+
     /// Calculate a predefined rolling aggregation
     ///
     /// See [`Rolling`] for additional functionality.
@@ -181,7 +258,8 @@ where
         Rolling::new(window, &self)
     }
 
-    /// Return an iterable of booleans determining if any element is NaN
+    /// Return an iterable of booleans determining if any element is missing;
+    /// either null or NaN for float series.
     ///
     /// ## Example
     /// ```
@@ -197,12 +275,168 @@ where
     /// // Insert a NaN at index zero
     /// series[0] = num::Float::nan();
     /// assert_eq!(series.isna().collect::<Vec<bool>>(), vec![true, false, false]);
+    ///
+    /// // Integer series can hold nulls as well
+    /// let series = Series::from_options(vec![Some(1), None, Some(3)]);
+    /// assert_eq!(series.isna().collect::<Vec<bool>>(), vec![false, true, false]);
+    /// ```
+    pub fn isna<'a>(&'a self) -> impl Iterator<Item = bool> + 'a {
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(idx, v)| self.is_null(idx) || v.is_na())
+    }
+
+    /// Return an iterable of booleans determining if any element is _not_ missing,
+    /// the inverse of [`Series::isna`]
+    pub fn notna<'a>(&'a self) -> impl Iterator<Item = bool> + 'a {
+        self.isna().map(|na| !na)
+    }
+
+    /// Count the elements of the series which are not missing
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![Some(1.), None, Some(num::Float::nan())]);
+    /// assert_eq!(series.count(), 1);
+    /// assert_eq!(series.len(), 3);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.notna().filter(|valid| *valid).count()
+    }
+
+    /// Count the elements of the series which are null, unlike [`Series::isna`]
+    /// this does not consider NaN values as null.
+    pub fn null_count(&self) -> usize {
+        match self.validity {
+            Some(ref validity) => validity.count_zeros(),
+            None => 0,
+        }
+    }
+
+    /// Determine if the element at a given position is null
+    pub fn is_null(&self, idx: usize) -> bool {
+        is_null_at(&self.validity, idx)
+    }
+
+    /// Get the validity bitmap of the series, `None` if no elements are null.
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Get a reference to the element at a given position, `None` if the
+    /// element is null or the position is out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![Some(1), None]);
+    /// assert_eq!(series.get(0), Some(&1));
+    /// assert_eq!(series.get(1), None);
+    /// assert_eq!(series.get(2), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if self.is_null(idx) {
+            None
+        } else {
+            self.values.get(idx)
+        }
+    }
+
+    /// Set the element at a given position, where `None` sets the element as null.
+    ///
+    /// ## Example
     /// ```
-    pub fn isna<'a>(&'a self) -> impl Iterator<Item = bool> + 'a
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![1, 2, 3]);
+    ///
+    /// series.set(1, None);
+    /// assert_eq!(series.count(), 2);
+    ///
+    /// series.set(1, Some(5));
+    /// assert_eq!(series.sum(), 9);
+    /// ```
+    pub fn set(&mut self, idx: usize, value: Option<T>) {
+        match value {
+            Some(value) => {
+                self.values[idx] = value;
+                if self.is_null(idx) {
+                    self.validity_mut().set(idx, true);
+                    self.validity = compact_validity(self.validity.take().unwrap());
+                }
+            }
+            None => self.set_null(idx),
+        }
+    }
+
+    /// Set the element at a given position as null
+    pub fn set_null(&mut self, idx: usize) {
+        self.values[idx] = T::default();
+        self.validity_mut().set(idx, false);
+    }
+
+    /// Append a null element to the Series
+    pub fn append_null(&mut self) {
+        self.validity_mut().push(false);
+        self.values.push(T::default());
+    }
+
+    // Get the validity bitmap, creating it as all valid if it doesn't exist
+    // and extending it if values were pushed without it.
+    fn validity_mut(&mut self) -> &mut Bitmap {
+        let len = self.values.len();
+        let validity = self.validity.get_or_insert_with(|| Bitmap::new(len, true));
+        while validity.len() < len {
+            validity.push(true);
+        }
+        validity
+    }
+
+    // Validity of an element-wise combination of this series and another.
+    pub(crate) fn combined_validity<O>(&self, other: &Series<O>) -> Option<Bitmap>
     where
-        T: Float,
+        O: BlackJackData,
     {
-        self.values.iter().map(|v| v.is_nan())
+        if self.validity.is_none() && other.validity.is_none() {
+            return None;
+        }
+        let validity = (0..self.len())
+            .map(|idx| !self.is_null(idx) && !other.is_null(idx))
+            .collect();
+        compact_validity(validity)
+    }
+
+    // The values which are not missing; only copies if there are missing values.
+    fn non_missing(&self) -> Cow<'_, [T]> {
+        if self.count() == self.len() {
+            Cow::Borrowed(self.values.as_slice())
+        } else {
+            Cow::Owned(
+                self.values
+                    .iter()
+                    .zip(self.isna())
+                    .filter(|(_, na)| !na)
+                    .map(|(v, _)| v.clone())
+                    .collect(),
+            )
+        }
+    }
+
+    // Each element, or `None` where it's null.
+    fn to_options(&self) -> Vec<Option<T>> {
+        (0..self.len()).map(|idx| self.get(idx).cloned()).collect()
+    }
+
+    // Series of `values`, with `None` as null, keeping the name and dtype of this series.
+    fn with_options(&self, values: Vec<Option<T>>) -> Series<T> {
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series.dtype = self.dtype.clone();
+        series
     }
 
     /// Determine if _all_ elements in the Series meet a given condition
@@ -331,7 +565,9 @@ where
         F: Fn(T) -> B + Send + Sync,
     {
         let new_data = self.values.into_par_iter().map(func).collect();
-        Series::from_vec(new_data)
+        let mut series = Series::from_vec(new_data);
+        series.validity = self.validity;
+        series
     }
 
     /// Map a function over a series in a single thread
@@ -343,7 +579,9 @@ where
         F: Fn(T) -> B,
     {
         let new_data = self.values.into_iter().map(func).collect();
-        Series::from_vec(new_data)
+        let mut series = Series::from_vec(new_data);
+        series.validity = self.validity;
+        series
     }
 
     /// Convert the series into another [`DType`] (creates a new series)
@@ -363,15 +601,23 @@ where
     {
         let values = self
             .values
-            .clone()
-            .into_iter()
-            .map(|v| v.to_string())
-            .map(|v| v.parse::<A>().map_err(|_| "Cannot cast into type"))
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| {
+                if self.is_null(idx) {
+                    Ok(A::default())
+                } else {
+                    v.to_string()
+                        .parse::<A>()
+                        .map_err(|_| "Cannot cast into type")
+                }
+            })
             .collect::<Result<Vec<A>, _>>()?;
         let series = Series {
             name: self.name.clone(),
             dtype: Some(values[0].dtype()),
             values,
+            validity: self.validity.clone(),
         };
         Ok(series)
     }
@@ -391,55 +637,30 @@ where
     where
         A: BlackJackData + FromStr,
     {
+        let validity = self.validity;
         let values = self
             .values
             .into_iter()
-            .map(|v| v.to_string())
-            .map(|v| v.parse::<A>().map_err(|_| "Cannot cast into type"))
+            .enumerate()
+            .map(|(idx, v)| {
+                if is_null_at(&validity, idx) {
+                    Ok(A::default())
+                } else {
+                    v.to_string()
+                        .parse::<A>()
+                        .map_err(|_| "Cannot cast into type")
+                }
+            })
             .collect::<Result<Vec<A>, _>>()?;
         let series = Series {
-            name: self.name.clone(),
+            name: self.name,
             dtype: Some(values[0].dtype()),
             values,
+            validity,
         };
         Ok(series)
     }
 
-    /// Get a series of the unique elements held in this series
-    ///
-    /// ## Example
-    ///
-    /// ```
-    /// use blackjack::prelude::*;
-    ///
-    /// let series: Series<i32> = Series::from_vec(vec![1, 2, 1, 0, 1, 0, 1, 1]);
-    /// let unique: Series<i32> = series.unique();
-    /// assert_eq!(unique, Series::from_vec(vec![0, 1, 2]));
-    /// ```
-    pub fn unique(&self) -> Series<T>
-    where
-        T: PartialOrd + Copy,
-    {
-        // Cannot use `HashSet` as f32 & f64 don't implement Hash
-        let mut unique: Vec<T> = vec![];
-        let mut values = self.values.clone();
-        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
-
-        for val in values {
-            if unique.len() > 0 {
-                if val == unique[unique.len() - 1] {
-                    continue;
-                } else {
-                    unique.push(val)
-                }
-            } else {
-                unique.push(val)
-            }
-        }
-
-        Series::from_vec(unique)
-    }
-
     /// Create a new Series struct from a vector, where T is supported by [`BlackJackData`].
     ///
     /// ## Example
@@ -458,9 +679,32 @@ where
             name: None,
             dtype,
             values: vec,
+            validity: None,
         }
     }
 
+    /// Create a new Series struct from a vector of options, where `None` elements
+    /// are stored as nulls.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series: Series<String> = Series::from_options(vec![Some("a".to_string()), None]);
+    /// assert_eq!(series.len(), 2);
+    /// assert_eq!(series.count(), 1);
+    /// ```
+    pub fn from_options(vec: Vec<Option<T>>) -> Self {
+        let validity: Bitmap = vec.iter().map(|v| v.is_some()).collect();
+        let values = vec
+            .into_iter()
+            .map(|v| v.unwrap_or_default())
+            .collect::<Vec<T>>();
+        let mut series = Series::from_vec(values);
+        series.validity = compact_validity(validity);
+        series
+    }
+
     /// Convert the series to a [`Vec`]
     ///
     /// ## Example
@@ -514,7 +758,7 @@ where
             ));
         }
 
-        let modes = stats::modes(self.values.iter().map(|v| *v));
+        let modes = stats::modes(self.non_missing().iter().map(|v| *v));
         let modes = Series::from_vec(modes);
         Ok(modes)
     }
@@ -531,7 +775,7 @@ where
                 "Cannot compute variance of an empty series!".to_owned(),
             ));
         }
-        funcs::variance(self.values.as_slice(), ddof)
+        funcs::variance(&self.non_missing(), ddof)
             .ok_or_else(|| BlackJackError::from("Failed to calculate variance of series."))
     }
 
@@ -556,17 +800,17 @@ where
                 "Cannot compute standard deviation of an empty series!".to_owned(),
             ));
         }
-        funcs::std(self.values.as_slice(), ddof)
+        funcs::std(&self.non_missing(), ddof)
             .ok_or_else(|| BlackJackError::from("Failed to calculate stddev of series."))
     }
 
     /// Sum a given series, yielding the same type as the elements stored in the
-    /// series.
+    /// series. Missing values are skipped.
     pub fn sum(&self) -> T
     where
-        T: Num + Copy + Sum,
+        T: Copy + Sum,
     {
-        funcs::sum(self.values.as_slice())
+        funcs::sum(&self.non_missing())
     }
 
     /// Average / Mean of a given series - Requires specifying desired float
@@ -591,9 +835,9 @@ where
     /// ```
     pub fn mean(&self) -> Result<f64, BlackJackError>
     where
-        T: ToPrimitive + Copy + Num + Sum,
+        T: ToPrimitive,
     {
-        funcs::mean(self.values.as_slice())
+        funcs::mean(&self.non_missing())
             .ok_or_else(|| BlackJackError::from("Failed to calculate mean!"))
     }
 
@@ -617,9 +861,8 @@ where
         use std::cmp::Ordering;
 
         let mut vec = self
-            .clone()
-            .into_vec()
-            .into_iter()
+            .non_missing()
+            .iter()
             .map(|v| v.to_f64().unwrap())
             .collect::<Vec<f64>>();
 
@@ -638,7 +881,7 @@ where
                 "Cannot calculate median of an empty series.",
             ));
         }
-        stats::median(self.values.iter().map(|v| v.to_f64().unwrap())).ok_or_else(|| {
+        stats::median(self.non_missing().iter().map(|v| v.to_f64().unwrap())).ok_or_else(|| {
             BlackJackError::from(
                 r#"Unable to calculate median, please create an issue!
                            as this wasn't expected to ever happen on a non-empty
@@ -660,9 +903,9 @@ where
     /// ```
     pub fn min(&self) -> Result<T, BlackJackError>
     where
-        T: Num + PartialOrd + BlackJackData + Copy,
+        T: PartialOrd + BlackJackData + Copy,
     {
-        funcs::min(self.values.as_slice())
+        funcs::min(&self.non_missing())
             .map(|v| *v)
             .ok_or_else(|| BlackJackError::from("Failed to calculate min of series."))
     }
@@ -671,9 +914,9 @@ where
     /// yielding the [`Result`] of a maximum.
     pub fn max(&self) -> Result<T, BlackJackError>
     where
-        T: Num + PartialOrd + BlackJackData + Copy,
+        T: PartialOrd + BlackJackData + Copy,
     {
-        funcs::max(self.values.as_slice())
+        funcs::max(&self.non_missing())
             .map(|v| *v)
             .ok_or_else(|| BlackJackError::from("Failed to calculate max of series."))
     }
@@ -709,6 +952,9 @@ where
     /// ```
     pub fn append<V: Into<T>>(&mut self, val: V) -> () {
         let v = val.into();
+        if self.validity.is_some() {
+            self.validity_mut().push(true);
+        }
         self.values.push(v);
     }
 
@@ -756,28 +1002,30 @@ where
         */
         use indexmap::IndexMap;
 
-        let values = self.values.clone();
+        let mut map: IndexMap<String, (Vec<Option<T>>, Vec<usize>)> = IndexMap::new();
 
-        let mut map: IndexMap<String, Vec<T>> = IndexMap::new();
-
-        // Group values by their keys
-        for (k, v) in keys.values.iter().zip(values.iter()) {
+        // Group values by their keys, elements with a missing key are dropped.
+        for (idx, (k, key_na)) in keys.values.iter().zip(keys.isna()).enumerate() {
+            if key_na || idx >= self.len() {
+                continue;
+            }
             let key = k.to_string();
-            let mr = map.entry(key).or_insert(vec![]);
-            mr.push(v.clone());
+            let mr = map.entry(key).or_insert((vec![], vec![]));
+            mr.0.push(self.get(idx).cloned());
+            mr.1.push(idx);
         }
 
         // Create new series from the previous mapping.
-        let groups = map
-            .iter()
-            .map(|(name, values)| {
-                let mut series = Series::from_vec(values.clone());
+        let (groups, positions) = map
+            .into_iter()
+            .map(|(name, (values, positions))| {
+                let mut series = Series::from_options(values);
                 series.set_name(name.as_str());
-                series
+                (series, positions)
             })
-            .collect();
+            .unzip();
 
-        SeriesGroupBy::new(groups)
+        SeriesGroupBy::from_positions(groups, positions, self.len())
     }
 
     /// Find the _positions_ where a condition is true
@@ -799,6 +1047,106 @@ where
             .map(|(idx, _val)| idx)
             .collect()
     }
+
+    /// Select the elements where a boolean mask is `true`, yielding a new series.
+    /// Null elements of the mask are treated as `false`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4]);
+    /// let mask = Series::from_vec(vec![true, false, true, false]);
+    ///
+    /// let selected = series.filter_by_mask(&mask).unwrap();
+    /// assert_eq!(selected.into_vec(), vec![1, 3]);
+    /// ```
+    pub fn filter_by_mask(&self, mask: &Series<bool>) -> Result<Series<T>, BlackJackError> {
+        if self.len() != mask.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Series has length: {}, cannot filter by mask of length: {}",
+                self.len(),
+                mask.len()
+            )));
+        }
+        let values = mask
+            .values
+            .iter()
+            .enumerate()
+            .filter(|(idx, keep)| **keep && !mask.is_null(*idx))
+            .map(|(idx, _)| self.get(idx).cloned())
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series.dtype = self.dtype.clone();
+        Ok(series)
+    }
+}
+
+// Null elements hold arbitrary placeholder values, so only valid elements are compared.
+impl<T> PartialEq for Series<T>
+where
+    T: BlackJackData + PartialEq,
+{
+    fn eq(&self, other: &Series<T>) -> bool {
+        self.name == other.name
+            && self.dtype == other.dtype
+            && self.validity == other.validity
+            && self.len() == other.len()
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .enumerate()
+                .all(|(idx, (a, b))| self.is_null(idx) || a == b)
+    }
+}
+
+/// Orders by name, then element-wise with nulls before any value, then by length and
+/// dtype; consistent with `PartialEq`, ignoring the placeholder values of nulls.
+impl<T> PartialOrd for Series<T>
+where
+    T: BlackJackData + PartialOrd,
+{
+    fn partial_cmp(&self, other: &Series<T>) -> Option<Ordering> {
+        match self.name.partial_cmp(&other.name)? {
+            Ordering::Equal => (),
+            ordering => return Some(ordering),
+        }
+        for idx in 0..self.len().min(other.len()) {
+            let ordering = match (self.is_null(idx), other.is_null(idx)) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => self.values[idx].partial_cmp(&other.values[idx])?,
+            };
+            if ordering != Ordering::Equal {
+                return Some(ordering);
+            }
+        }
+        match self.len().cmp(&other.len()) {
+            Ordering::Equal => self.dtype.partial_cmp(&other.dtype),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+// Determine if a position is null given a (possibly absent) validity bitmap
+fn is_null_at(validity: &Option<Bitmap>, idx: usize) -> bool {
+    match validity {
+        Some(validity) => validity.get(idx) == Some(false),
+        None => false,
+    }
+}
+
+// Drop the validity bitmap if it holds no nulls, so series compare equal
+// regardless of how they arrived at being fully valid.
+fn compact_validity(validity: Bitmap) -> Option<Bitmap> {
+    if validity.count_zeros() == 0 {
+        None
+    } else {
+        Some(validity)
+    }
 }
 
 // Support Series creation from Range
@@ -864,8 +1212,13 @@ where
         let _ = self
             .values
             .iter()
-            .map(|v| {
-                let v: String = v.clone().into();
+            .enumerate()
+            .map(|(idx, v)| {
+                let v: String = if self.is_null(idx) {
+                    "<NA>".to_string()
+                } else {
+                    v.clone().into()
+                };
                 table.add_row(Row::new(vec![Cell::new(&format!("{}", v))]));
             })
             .collect::<Vec<()>>();
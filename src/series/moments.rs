@@ -0,0 +1,84 @@
+//! Higher moments and robust statistics of a numeric `Series`, skipping missing
+//! values; see [`SeriesGroupBy`] and [`Rolling`] for the same per group and window.
+//!
+//! As with [`Series::var`], `ddof` of `0_f64` gives the population standard error, and
+//! `1_f64` the sample standard error. Skewness and kurtosis instead take `bias`, giving
+//! the population statistic if `true`, otherwise the sample statistic adjusted for bias.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![1., 2., 3., 4., 10.]);
+//!
+//! assert!(series.skew(false).unwrap() > 1.);
+//! assert!(series.kurtosis(false).unwrap() > 1.);
+//! assert_eq!(Series::from_vec(vec![0., 0., 4., 4.]).sem(0.).unwrap(), 1.);
+//! assert_eq!(series.mean_abs_dev().unwrap(), 2.4);
+//! assert_eq!(series.median_abs_dev().unwrap(), 1.);
+//! ```
+
+use num::*;
+
+use crate::funcs;
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData + ToPrimitive,
+{
+    /// Calculate the skewness, adjusted for bias unless `bias`; which requires at
+    /// least 3 values, otherwise 1.
+    pub fn skew(&self, bias: bool) -> Result<f64, BlackJackError> {
+        funcs::skew(&self.non_missing(), bias)
+            .ok_or_else(|| BlackJackError::from("Not enough values to calculate skew of series."))
+    }
+
+    /// Calculate the excess kurtosis, zero for a normal distribution, adjusted for
+    /// bias unless `bias`; which requires at least 4 values, otherwise 1.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(series.kurtosis(true).unwrap(), -1.36);
+    /// assert!((series.kurtosis(false).unwrap() + 1.2).abs() < 1e-12);
+    /// assert!(Series::from_vec(vec![1, 2, 3]).kurtosis(false).is_err());
+    /// ```
+    pub fn kurtosis(&self, bias: bool) -> Result<f64, BlackJackError> {
+        funcs::kurtosis(&self.non_missing(), bias).ok_or_else(|| {
+            BlackJackError::from("Not enough values to calculate kurtosis of series.")
+        })
+    }
+
+    /// Calculate the standard error of the mean, the standard deviation with `ddof`
+    /// divided by the square root of the number of values
+    pub fn sem(&self, ddof: f64) -> Result<f64, BlackJackError>
+    where
+        T: Num,
+    {
+        if self.count() == 0 {
+            return Err(BlackJackError::ValueError(
+                "Cannot compute standard error of an empty series!".to_owned(),
+            ));
+        }
+        funcs::sem(&self.non_missing(), ddof)
+            .ok_or_else(|| BlackJackError::from("Failed to calculate sem of series."))
+    }
+
+    /// Calculate the mean absolute deviation of the values from their mean
+    pub fn mean_abs_dev(&self) -> Result<f64, BlackJackError> {
+        funcs::mean_abs_dev(&self.non_missing()).ok_or_else(|| {
+            BlackJackError::from("Cannot calculate mean absolute deviation of an empty series.")
+        })
+    }
+
+    /// Calculate the median absolute deviation of the values from their median
+    pub fn median_abs_dev(&self) -> Result<f64, BlackJackError> {
+        funcs::median_abs_dev(&self.non_missing()).ok_or_else(|| {
+            BlackJackError::from("Cannot calculate median absolute deviation of an empty series.")
+        })
+    }
+}
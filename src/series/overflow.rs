@@ -0,0 +1,331 @@
+//! Integer arithmetic on `Series` with explicit overflow behaviour, rather than the
+//! operators which panic on overflow in debug builds and wrap in release builds.
+//!
+//! - `checked_*`: a `ValueError` giving the position of the first overflow, or of
+//!   a division by zero.
+//! - `saturating_*`: clamp to the bounds of the dtype.
+//! - `wrapping_*`: wrap around the bounds of the dtype.
+//!
+//! All three families add, subtract, multiply and divide. Division by zero has no
+//! saturated or wrapped result, so it is a `ValueError` for every family.
+//!
+//! Operations between series return a `LengthMismatch` error if the lengths differ,
+//! and are null where either element is null.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![100_i8, 120]);
+//! let other = Series::from_vec(vec![20_i8, 20]);
+//!
+//! assert!(series.checked_add(&other).is_err());
+//! assert_eq!(series.saturating_add(&other).unwrap().values, vec![120, 127]);
+//! assert_eq!(series.wrapping_add(&other).unwrap().values, vec![120, -116]);
+//!
+//! assert_eq!(series.checked_mul_scalar(1).unwrap().values, vec![100, 120]);
+//! assert_eq!(series.saturating_mul_scalar(-2).values, vec![-128, -128]);
+//! ```
+
+use num::traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub,
+    WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// `self + other`, or an error at the first overflow
+    pub fn checked_add(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedAdd,
+    {
+        self.zip_checked(other, "checked_add", |a, b| a.checked_add(b))
+    }
+
+    /// `self - other`, or an error at the first overflow
+    pub fn checked_sub(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedSub,
+    {
+        self.zip_checked(other, "checked_sub", |a, b| a.checked_sub(b))
+    }
+
+    /// `self * other`, or an error at the first overflow
+    pub fn checked_mul(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedMul,
+    {
+        self.zip_checked(other, "checked_mul", |a, b| a.checked_mul(b))
+    }
+
+    /// `self / other`, or an error at the first overflow or division by zero
+    pub fn checked_div(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedDiv,
+    {
+        self.zip_checked(other, "checked_div", |a, b| a.checked_div(b))
+    }
+
+    /// `self + scalar`, or an error at the first overflow
+    pub fn checked_add_scalar(&self, scalar: T) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedAdd,
+    {
+        self.map_checked("checked_add_scalar", |a| a.checked_add(&scalar))
+    }
+
+    /// `self - scalar`, or an error at the first overflow
+    pub fn checked_sub_scalar(&self, scalar: T) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedSub,
+    {
+        self.map_checked("checked_sub_scalar", |a| a.checked_sub(&scalar))
+    }
+
+    /// `self * scalar`, or an error at the first overflow
+    pub fn checked_mul_scalar(&self, scalar: T) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedMul,
+    {
+        self.map_checked("checked_mul_scalar", |a| a.checked_mul(&scalar))
+    }
+
+    /// `self / scalar`, or an error at the first overflow or if `scalar` is zero
+    pub fn checked_div_scalar(&self, scalar: T) -> Result<Series<T>, BlackJackError>
+    where
+        T: CheckedDiv,
+    {
+        self.map_checked("checked_div_scalar", |a| a.checked_div(&scalar))
+    }
+
+    /// `self + other`, clamped to the bounds of the dtype
+    pub fn saturating_add(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: SaturatingAdd,
+    {
+        self.zip_checked(other, "saturating_add", |a, b| Some(a.saturating_add(b)))
+    }
+
+    /// `self - other`, clamped to the bounds of the dtype
+    pub fn saturating_sub(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: SaturatingSub,
+    {
+        self.zip_checked(other, "saturating_sub", |a, b| Some(a.saturating_sub(b)))
+    }
+
+    /// `self * other`, clamped to the bounds of the dtype
+    pub fn saturating_mul(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: SaturatingMul,
+    {
+        self.zip_checked(other, "saturating_mul", |a, b| Some(a.saturating_mul(b)))
+    }
+
+    /// `self / other`, clamped to the bounds of the dtype, or an error at the first
+    /// division by zero
+    pub fn saturating_div(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: OverflowDiv + Zero,
+    {
+        self.zip_checked(other, "saturating_div", |a, b| {
+            non_zero(b).map(|b| a.saturating_div(b))
+        })
+    }
+
+    /// `self + scalar`, clamped to the bounds of the dtype
+    pub fn saturating_add_scalar(&self, scalar: T) -> Series<T>
+    where
+        T: SaturatingAdd,
+    {
+        self.map_unchecked(|a| a.saturating_add(&scalar))
+    }
+
+    /// `self - scalar`, clamped to the bounds of the dtype
+    pub fn saturating_sub_scalar(&self, scalar: T) -> Series<T>
+    where
+        T: SaturatingSub,
+    {
+        self.map_unchecked(|a| a.saturating_sub(&scalar))
+    }
+
+    /// `self * scalar`, clamped to the bounds of the dtype
+    pub fn saturating_mul_scalar(&self, scalar: T) -> Series<T>
+    where
+        T: SaturatingMul,
+    {
+        self.map_unchecked(|a| a.saturating_mul(&scalar))
+    }
+
+    /// `self / scalar`, clamped to the bounds of the dtype, or an error if `scalar`
+    /// is zero
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![i32::MIN, 10]);
+    ///
+    /// assert_eq!(series.saturating_div_scalar(-1).unwrap().values, vec![i32::MAX, -10]);
+    /// assert_eq!(series.wrapping_div_scalar(-1).unwrap().values, vec![i32::MIN, -10]);
+    /// assert!(series.saturating_div_scalar(0).is_err());
+    /// ```
+    pub fn saturating_div_scalar(&self, scalar: T) -> Result<Series<T>, BlackJackError>
+    where
+        T: OverflowDiv + Zero,
+    {
+        self.map_checked("saturating_div_scalar", |a| {
+            non_zero(&scalar).map(|scalar| a.saturating_div(scalar))
+        })
+    }
+
+    /// `self + other`, wrapping around the bounds of the dtype
+    pub fn wrapping_add(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: WrappingAdd,
+    {
+        self.zip_checked(other, "wrapping_add", |a, b| Some(a.wrapping_add(b)))
+    }
+
+    /// `self - other`, wrapping around the bounds of the dtype
+    pub fn wrapping_sub(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: WrappingSub,
+    {
+        self.zip_checked(other, "wrapping_sub", |a, b| Some(a.wrapping_sub(b)))
+    }
+
+    /// `self * other`, wrapping around the bounds of the dtype
+    pub fn wrapping_mul(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: WrappingMul,
+    {
+        self.zip_checked(other, "wrapping_mul", |a, b| Some(a.wrapping_mul(b)))
+    }
+
+    /// `self / other`, wrapping around the bounds of the dtype, or an error at the
+    /// first division by zero
+    pub fn wrapping_div(&self, other: &Series<T>) -> Result<Series<T>, BlackJackError>
+    where
+        T: OverflowDiv + Zero,
+    {
+        self.zip_checked(other, "wrapping_div", |a, b| {
+            non_zero(b).map(|b| a.wrapping_div(b))
+        })
+    }
+
+    /// `self + scalar`, wrapping around the bounds of the dtype
+    pub fn wrapping_add_scalar(&self, scalar: T) -> Series<T>
+    where
+        T: WrappingAdd,
+    {
+        self.map_unchecked(|a| a.wrapping_add(&scalar))
+    }
+
+    /// `self - scalar`, wrapping around the bounds of the dtype
+    pub fn wrapping_sub_scalar(&self, scalar: T) -> Series<T>
+    where
+        T: WrappingSub,
+    {
+        self.map_unchecked(|a| a.wrapping_sub(&scalar))
+    }
+
+    /// `self * scalar`, wrapping around the bounds of the dtype
+    pub fn wrapping_mul_scalar(&self, scalar: T) -> Series<T>
+    where
+        T: WrappingMul,
+    {
+        self.map_unchecked(|a| a.wrapping_mul(&scalar))
+    }
+
+    /// `self / scalar`, wrapping around the bounds of the dtype, or an error if `scalar`
+    /// is zero
+    pub fn wrapping_div_scalar(&self, scalar: T) -> Result<Series<T>, BlackJackError>
+    where
+        T: OverflowDiv + Zero,
+    {
+        self.map_checked("wrapping_div_scalar", |a| {
+            non_zero(&scalar).map(|scalar| a.wrapping_div(scalar))
+        })
+    }
+
+    // Combine elementwise with `other` by `op`, skipping nulls, failing where it gives `None`
+    fn zip_checked<F>(
+        &self,
+        other: &Series<T>,
+        op_name: &str,
+        op: F,
+    ) -> Result<Series<T>, BlackJackError>
+    where
+        F: Fn(&T, &T) -> Option<T>,
+    {
+        if self.len() != other.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Source series is of size: {}, and other is of size: {}",
+                self.len(),
+                other.len()
+            )));
+        }
+        let mut series = self.clone();
+        series.validity = self.combined_validity(other);
+        for idx in 0..self.len() {
+            if series.is_null(idx) {
+                series.values[idx] = T::default();
+                continue;
+            }
+            series.values[idx] = op(&self.values[idx], &other.values[idx]).ok_or_else(|| {
+                overflow_error(op_name, idx, &self.values[idx], Some(&other.values[idx]))
+            })?;
+        }
+        Ok(series)
+    }
+
+    // Apply `op` to each element, skipping nulls, failing where it gives `None`
+    fn map_checked<F>(&self, op_name: &str, op: F) -> Result<Series<T>, BlackJackError>
+    where
+        F: Fn(&T) -> Option<T>,
+    {
+        let mut series = self.clone();
+        for idx in (0..self.len()).filter(|idx| !self.is_null(*idx)) {
+            series.values[idx] = op(&self.values[idx])
+                .ok_or_else(|| overflow_error(op_name, idx, &self.values[idx], None))?;
+        }
+        Ok(series)
+    }
+
+    fn map_unchecked<F>(&self, op: F) -> Series<T>
+    where
+        F: Fn(&T) -> T,
+    {
+        self.map_checked("", |value| Some(op(value))).unwrap()
+    }
+}
+
+// The divisor, unless it's zero
+fn non_zero<T: Zero>(divisor: &T) -> Option<&T> {
+    if divisor.is_zero() {
+        None
+    } else {
+        Some(divisor)
+    }
+}
+
+fn overflow_error<T: BlackJackData>(
+    op_name: &str,
+    idx: usize,
+    value: &T,
+    other: Option<&T>,
+) -> BlackJackError {
+    let operands = match other {
+        Some(other) => format!("{} and {}", value, other),
+        None => value.to_string(),
+    };
+    BlackJackError::ValueError(format!(
+        "Integer overflow or division by zero in {} at position {}, with {}",
+        op_name, idx, operands
+    ))
+}
@@ -3,7 +3,9 @@
 //!
 
 use std::marker::Send;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitOr, Div, DivAssign, Mul, MulAssign, Not, Sub, SubAssign,
+};
 
 use num::*;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator};
@@ -26,7 +28,9 @@ where
             .into_par_iter()
             .map(|v| v * scalar_val)
             .collect();
-        Series::from_vec(vec)
+        let mut series = Series::from_vec(vec);
+        series.validity = self.validity;
+        series
     }
 }
 
@@ -50,11 +54,367 @@ impl_series_by_series_op!(Sub, sub, -);
 impl_series_by_series_op!(Div, div, /);
 impl_series_by_series_op!(Mul, mul, *);
 
+// Support `series + other_series` across numeric dtypes, promoting to a common dtype
+// able to represent both; see `DType::promote`
+impl_series_promotion!(f64, i64 => f64);
+impl_series_promotion!(f64, f32 => f64);
+impl_series_promotion!(f64, i32 => f64);
+impl_series_promotion!(f64, i16 => f64);
+impl_series_promotion!(f64, i8 => f64);
+impl_series_promotion!(f64, u64 => f64);
+impl_series_promotion!(f64, u32 => f64);
+impl_series_promotion!(f64, u16 => f64);
+impl_series_promotion!(f64, u8 => f64);
+impl_series_promotion!(i64, f32 => f64);
+impl_series_promotion!(i64, i32 => i64);
+impl_series_promotion!(i64, i16 => i64);
+impl_series_promotion!(i64, i8 => i64);
+impl_series_promotion!(i64, u64 => f64);
+impl_series_promotion!(i64, u32 => i64);
+impl_series_promotion!(i64, u16 => i64);
+impl_series_promotion!(i64, u8 => i64);
+impl_series_promotion!(f32, i32 => f64);
+impl_series_promotion!(f32, i16 => f32);
+impl_series_promotion!(f32, i8 => f32);
+impl_series_promotion!(f32, u64 => f64);
+impl_series_promotion!(f32, u32 => f64);
+impl_series_promotion!(f32, u16 => f32);
+impl_series_promotion!(f32, u8 => f32);
+impl_series_promotion!(i32, i16 => i32);
+impl_series_promotion!(i32, i8 => i32);
+impl_series_promotion!(i32, u64 => f64);
+impl_series_promotion!(i32, u32 => i64);
+impl_series_promotion!(i32, u16 => i32);
+impl_series_promotion!(i32, u8 => i32);
+impl_series_promotion!(i16, i8 => i16);
+impl_series_promotion!(i16, u64 => f64);
+impl_series_promotion!(i16, u32 => i64);
+impl_series_promotion!(i16, u16 => i32);
+impl_series_promotion!(i16, u8 => i16);
+impl_series_promotion!(i8, u64 => f64);
+impl_series_promotion!(i8, u32 => i64);
+impl_series_promotion!(i8, u16 => i32);
+impl_series_promotion!(i8, u8 => i16);
+impl_series_promotion!(u64, u32 => u64);
+impl_series_promotion!(u64, u16 => u64);
+impl_series_promotion!(u64, u8 => u64);
+impl_series_promotion!(u32, u16 => u32);
+impl_series_promotion!(u32, u8 => u32);
+impl_series_promotion!(u16, u8 => u16);
+
+// Support `series + scalar` with a scalar of another numeric dtype, promoting to a
+// common dtype as for `series + other_series`
+impl_scalar_promotion!(f64, i64 => f64);
+impl_scalar_promotion!(f64, f32 => f64);
+impl_scalar_promotion!(f64, i32 => f64);
+impl_scalar_promotion!(f64, i16 => f64);
+impl_scalar_promotion!(f64, i8 => f64);
+impl_scalar_promotion!(f64, u64 => f64);
+impl_scalar_promotion!(f64, u32 => f64);
+impl_scalar_promotion!(f64, u16 => f64);
+impl_scalar_promotion!(f64, u8 => f64);
+impl_scalar_promotion!(i64, f64 => f64);
+impl_scalar_promotion!(i64, f32 => f64);
+impl_scalar_promotion!(i64, i32 => i64);
+impl_scalar_promotion!(i64, i16 => i64);
+impl_scalar_promotion!(i64, i8 => i64);
+impl_scalar_promotion!(i64, u64 => f64);
+impl_scalar_promotion!(i64, u32 => i64);
+impl_scalar_promotion!(i64, u16 => i64);
+impl_scalar_promotion!(i64, u8 => i64);
+impl_scalar_promotion!(f32, f64 => f64);
+impl_scalar_promotion!(f32, i64 => f64);
+impl_scalar_promotion!(f32, i32 => f64);
+impl_scalar_promotion!(f32, i16 => f32);
+impl_scalar_promotion!(f32, i8 => f32);
+impl_scalar_promotion!(f32, u64 => f64);
+impl_scalar_promotion!(f32, u32 => f64);
+impl_scalar_promotion!(f32, u16 => f32);
+impl_scalar_promotion!(f32, u8 => f32);
+impl_scalar_promotion!(i32, f64 => f64);
+impl_scalar_promotion!(i32, i64 => i64);
+impl_scalar_promotion!(i32, f32 => f64);
+impl_scalar_promotion!(i32, i16 => i32);
+impl_scalar_promotion!(i32, i8 => i32);
+impl_scalar_promotion!(i32, u64 => f64);
+impl_scalar_promotion!(i32, u32 => i64);
+impl_scalar_promotion!(i32, u16 => i32);
+impl_scalar_promotion!(i32, u8 => i32);
+impl_scalar_promotion!(i16, f64 => f64);
+impl_scalar_promotion!(i16, i64 => i64);
+impl_scalar_promotion!(i16, f32 => f32);
+impl_scalar_promotion!(i16, i32 => i32);
+impl_scalar_promotion!(i16, i8 => i16);
+impl_scalar_promotion!(i16, u64 => f64);
+impl_scalar_promotion!(i16, u32 => i64);
+impl_scalar_promotion!(i16, u16 => i32);
+impl_scalar_promotion!(i16, u8 => i16);
+impl_scalar_promotion!(i8, f64 => f64);
+impl_scalar_promotion!(i8, i64 => i64);
+impl_scalar_promotion!(i8, f32 => f32);
+impl_scalar_promotion!(i8, i32 => i32);
+impl_scalar_promotion!(i8, i16 => i16);
+impl_scalar_promotion!(i8, u64 => f64);
+impl_scalar_promotion!(i8, u32 => i64);
+impl_scalar_promotion!(i8, u16 => i32);
+impl_scalar_promotion!(i8, u8 => i16);
+impl_scalar_promotion!(u64, f64 => f64);
+impl_scalar_promotion!(u64, i64 => f64);
+impl_scalar_promotion!(u64, f32 => f64);
+impl_scalar_promotion!(u64, i32 => f64);
+impl_scalar_promotion!(u64, i16 => f64);
+impl_scalar_promotion!(u64, i8 => f64);
+impl_scalar_promotion!(u64, u32 => u64);
+impl_scalar_promotion!(u64, u16 => u64);
+impl_scalar_promotion!(u64, u8 => u64);
+impl_scalar_promotion!(u32, f64 => f64);
+impl_scalar_promotion!(u32, i64 => i64);
+impl_scalar_promotion!(u32, f32 => f64);
+impl_scalar_promotion!(u32, i32 => i64);
+impl_scalar_promotion!(u32, i16 => i64);
+impl_scalar_promotion!(u32, i8 => i64);
+impl_scalar_promotion!(u32, u64 => u64);
+impl_scalar_promotion!(u32, u16 => u32);
+impl_scalar_promotion!(u32, u8 => u32);
+impl_scalar_promotion!(u16, f64 => f64);
+impl_scalar_promotion!(u16, i64 => i64);
+impl_scalar_promotion!(u16, f32 => f32);
+impl_scalar_promotion!(u16, i32 => i32);
+impl_scalar_promotion!(u16, i16 => i32);
+impl_scalar_promotion!(u16, i8 => i32);
+impl_scalar_promotion!(u16, u64 => u64);
+impl_scalar_promotion!(u16, u32 => u32);
+impl_scalar_promotion!(u16, u8 => u16);
+impl_scalar_promotion!(u8, f64 => f64);
+impl_scalar_promotion!(u8, i64 => i64);
+impl_scalar_promotion!(u8, f32 => f32);
+impl_scalar_promotion!(u8, i32 => i32);
+impl_scalar_promotion!(u8, i16 => i16);
+impl_scalar_promotion!(u8, i8 => i16);
+impl_scalar_promotion!(u8, u64 => u64);
+impl_scalar_promotion!(u8, u32 => u32);
+impl_scalar_promotion!(u8, u16 => u16);
+
 // Support `series += other_series`
-impl_series_by_series_op_inplace!(MulAssign, mul_assign, *=);
-impl_series_by_series_op_inplace!(DivAssign, div_assign, /=);
-impl_series_by_series_op_inplace!(AddAssign, add_assign, +=);
-impl_series_by_series_op_inplace!(SubAssign, sub_assign, -=);
+impl_series_by_series_op_inplace!(MulAssign, mul_assign, try_mul_assign);
+impl_series_by_series_op_inplace!(DivAssign, div_assign, try_div_assign);
+impl_series_by_series_op_inplace!(AddAssign, add_assign, try_add_assign);
+impl_series_by_series_op_inplace!(SubAssign, sub_assign, try_sub_assign);
+
+/// Fallible versions of `series += other_series` and friends, which return a
+/// `LengthMismatch` error, rather than panic, if the lengths differ; the result is
+/// null where either element is null.
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let mut series = Series::from_vec(vec![1, 2, 3]);
+/// assert!(series.try_add_assign(Series::from_vec(vec![1, 1, 1])).is_ok());
+/// assert_eq!(series.values, vec![2, 3, 4]);
+///
+/// assert!(series.try_add_assign(Series::from_vec(vec![1, 1])).is_err());
+/// assert_eq!(series.values, vec![2, 3, 4]);
+/// ```
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// `self += other`, or a `LengthMismatch` error leaving `self` unchanged
+    pub fn try_add_assign(&mut self, other: Series<T>) -> Result<(), BlackJackError>
+    where
+        T: AddAssign,
+    {
+        self.zip_assign(other, |v, o| *v += o)
+    }
+
+    /// `self -= other`, or a `LengthMismatch` error leaving `self` unchanged
+    pub fn try_sub_assign(&mut self, other: Series<T>) -> Result<(), BlackJackError>
+    where
+        T: SubAssign,
+    {
+        self.zip_assign(other, |v, o| *v -= o)
+    }
+
+    /// `self *= other`, or a `LengthMismatch` error leaving `self` unchanged
+    pub fn try_mul_assign(&mut self, other: Series<T>) -> Result<(), BlackJackError>
+    where
+        T: MulAssign,
+    {
+        self.zip_assign(other, |v, o| *v *= o)
+    }
+
+    /// `self /= other`, or a `LengthMismatch` error leaving `self` unchanged
+    pub fn try_div_assign(&mut self, other: Series<T>) -> Result<(), BlackJackError>
+    where
+        T: DivAssign,
+    {
+        self.zip_assign(other, |v, o| *v /= o)
+    }
+
+    fn zip_assign<F>(&mut self, other: Series<T>, op: F) -> Result<(), BlackJackError>
+    where
+        F: Fn(&mut T, T),
+    {
+        if self.len() != other.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Source series is of size: {}, and other is of size: {}",
+                self.len(),
+                other.len()
+            )));
+        }
+        // Null slots are skipped, as their default may not be a valid operand
+        let validity = self.combined_validity(&other);
+        for (idx, o) in other.values.into_iter().enumerate() {
+            if validity.as_ref().and_then(|validity| validity.get(idx)) == Some(false) {
+                self.values[idx] = T::default();
+            } else {
+                op(&mut self.values[idx], o);
+            }
+        }
+        self.validity = validity;
+        Ok(())
+    }
+}
+
+/// Support `mask & other_mask`, null where either element is null
+impl BitAnd for Series<bool> {
+    type Output = Result<Series<bool>, BlackJackError>;
+
+    fn bitand(self, other: Series<bool>) -> Self::Output {
+        combine_masks(self, other, |a, b| a && b)
+    }
+}
+
+/// Support `mask | other_mask`, null where either element is null
+impl BitOr for Series<bool> {
+    type Output = Result<Series<bool>, BlackJackError>;
+
+    fn bitor(self, other: Series<bool>) -> Self::Output {
+        combine_masks(self, other, |a, b| a || b)
+    }
+}
+
+/// Support `!mask`, null elements remain null
+impl Not for Series<bool> {
+    type Output = Series<bool>;
+
+    fn not(self) -> Series<bool> {
+        let mut mask = self;
+        mask.values.iter_mut().for_each(|v| *v = !*v);
+        mask
+    }
+}
+
+fn combine_masks<F>(
+    left: Series<bool>,
+    right: Series<bool>,
+    op: F,
+) -> Result<Series<bool>, BlackJackError>
+where
+    F: Fn(bool, bool) -> bool,
+{
+    if left.len() != right.len() {
+        return Err(BlackJackError::LengthMismatch(format!(
+            "Source series is of size: {}, and other is of size: {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    let validity = left.combined_validity(&right);
+    let values = left
+        .values
+        .iter()
+        .zip(right.values.iter())
+        .map(|(a, b)| op(*a, *b))
+        .collect();
+    let mut mask = Series::from_vec(values);
+    mask.name = left.name;
+    mask.validity = validity;
+    Ok(mask)
+}
+
+/// Support `datetimes - other_datetimes`, yielding the elapsed durations
+impl Sub for Series<DateTime> {
+    type Output = Result<Series<Duration>, BlackJackError>;
+
+    fn sub(self, other: Series<DateTime>) -> Self::Output {
+        zip_elements(self, other, |a, b| a - b)
+    }
+}
+
+/// Support `datetimes + durations`
+impl Add<Series<Duration>> for Series<DateTime> {
+    type Output = Result<Series<DateTime>, BlackJackError>;
+
+    fn add(self, other: Series<Duration>) -> Self::Output {
+        zip_elements(self, other, |a, b| a + b)
+    }
+}
+
+/// Support `datetimes - durations`
+impl Sub<Series<Duration>> for Series<DateTime> {
+    type Output = Result<Series<DateTime>, BlackJackError>;
+
+    fn sub(self, other: Series<Duration>) -> Self::Output {
+        zip_elements(self, other, |a, b| a - b)
+    }
+}
+
+/// Support `datetimes + duration`
+impl Add<Duration> for Series<DateTime> {
+    type Output = Series<DateTime>;
+
+    fn add(self, duration: Duration) -> Series<DateTime> {
+        let mut series = self;
+        series.values.iter_mut().for_each(|v| *v = *v + duration);
+        series
+    }
+}
+
+/// Support `datetimes - duration`
+impl Sub<Duration> for Series<DateTime> {
+    type Output = Series<DateTime>;
+
+    fn sub(self, duration: Duration) -> Series<DateTime> {
+        self + -duration
+    }
+}
+
+fn zip_elements<A, B, O, F>(
+    left: Series<A>,
+    right: Series<B>,
+    op: F,
+) -> Result<Series<O>, BlackJackError>
+where
+    A: BlackJackData + Copy,
+    B: BlackJackData + Copy,
+    O: BlackJackData,
+    F: Fn(A, B) -> O,
+{
+    if left.len() != right.len() {
+        return Err(BlackJackError::LengthMismatch(format!(
+            "Source series is of size: {}, and other is of size: {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    // Null slots are skipped, as their default may not be a valid operand
+    let validity = left.combined_validity(&right);
+    let values = (0..left.len())
+        .map(|idx| {
+            if left.is_null(idx) || right.is_null(idx) {
+                O::default()
+            } else {
+                op(left.values[idx], right.values[idx])
+            }
+        })
+        .collect();
+    let mut series = Series::from_vec(values);
+    series.name = left.name;
+    series.validity = validity;
+    Ok(series)
+}
 
 /// Support `series + scalar`
 impl<T> Add<T> for Series<T>
@@ -71,7 +431,9 @@ where
             .into_par_iter()
             .map(|v| v + scalar_val)
             .collect();
-        Series::from_vec(vec)
+        let mut series = Series::from_vec(vec);
+        series.validity = self.validity;
+        series
     }
 }
 
@@ -105,7 +467,9 @@ where
             .into_par_iter()
             .map(|v| v - scalar_val)
             .collect();
-        Series::from_vec(vec)
+        let mut series = Series::from_vec(vec);
+        series.validity = self.validity;
+        series
     }
 }
 
@@ -139,7 +503,9 @@ where
             .into_par_iter()
             .map(|v| v / scalar_val)
             .collect();
-        Series::from_vec(vec)
+        let mut series = Series::from_vec(vec);
+        series.validity = self.validity;
+        series
     }
 }
 
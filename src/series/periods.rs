@@ -0,0 +1,104 @@
+//! Period over period operations on `Series`; shifting elements and comparing
+//! each element with the one a number of periods before it.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![10., 12., 9.]);
+//!
+//! let shifted = series.shift(1);
+//! assert_eq!(shifted.get(0), None);
+//! assert_eq!(shifted.values[1..], [10., 12.]);
+//!
+//! assert_eq!(series.diff(1).values[1..], [2., -3.]);
+//! assert_eq!(series.pct_change(1).values[1..], [0.2, -0.25]);
+//! ```
+
+use num::ToPrimitive;
+
+use crate::prelude::*;
+
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// Shift the elements by a number of periods, forwards if positive and backwards
+    /// if negative, keeping the length. Vacated positions are null.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// let shifted = series.shift(-2);
+    ///
+    /// assert_eq!(shifted.get(0), Some(&"c".to_string()));
+    /// assert_eq!(shifted.null_count(), 2);
+    /// ```
+    pub fn shift(&self, periods: i64) -> Series<T> {
+        let values = (0..self.len())
+            .map(|idx| {
+                self.lagged(idx, periods)
+                    .and_then(|lag| self.get(lag).cloned())
+            })
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series.dtype = self.dtype.clone();
+        series
+    }
+
+    /// Difference between each element and the element `periods` before it; null where
+    /// either is missing.
+    pub fn diff(&self, periods: i64) -> Series<f64>
+    where
+        T: ToPrimitive,
+    {
+        self.compare_lagged(periods, |current, previous| current - previous)
+    }
+
+    /// Fractional change between each element and the element `periods` before it;
+    /// null where either is missing.
+    pub fn pct_change(&self, periods: i64) -> Series<f64>
+    where
+        T: ToPrimitive,
+    {
+        self.compare_lagged(periods, |current, previous| (current - previous) / previous)
+    }
+
+    // Position `periods` before `idx`, if within the series
+    fn lagged(&self, idx: usize, periods: i64) -> Option<usize> {
+        let lag = (idx as i64).checked_sub(periods)?;
+        if lag >= 0 && lag < self.len() as i64 {
+            Some(lag as usize)
+        } else {
+            None
+        }
+    }
+
+    fn compare_lagged<F>(&self, periods: i64, op: F) -> Series<f64>
+    where
+        T: ToPrimitive,
+        F: Fn(f64, f64) -> f64,
+    {
+        let na = self.isna().collect::<Vec<bool>>();
+        let value = |idx: usize| {
+            if na[idx] {
+                None
+            } else {
+                self.values[idx].to_f64()
+            }
+        };
+        let values = (0..self.len())
+            .map(|idx| {
+                let previous = self.lagged(idx, periods).and_then(value)?;
+                Some(op(value(idx)?, previous))
+            })
+            .collect();
+        let mut series = Series::from_options(values);
+        series.name = self.name.clone();
+        series.dtype = Some(DType::F64);
+        series
+    }
+}
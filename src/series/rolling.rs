@@ -10,7 +10,7 @@ use rayon::prelude::*;
 use crate::funcs;
 use crate::prelude::*;
 
-/// Struct for calculating rolling aggregations
+/// Struct for calculating rolling aggregations; windows containing a null are `NaN`
 ///
 /// ## Example
 /// ```
@@ -39,6 +39,8 @@ where
     window: usize,
     series: &'a Series<T>,
     nans: Vec<f64>,
+    // Number of nulls before each position, to find windows containing a null
+    nulls_before: Vec<usize>,
 }
 
 // TODO: These impls need to be refactored (DRY) - lots of repeated code
@@ -58,13 +60,25 @@ where
     /// ```
     pub fn new(window: usize, series: &'a Series<T>) -> Self {
         let nans: Vec<f64> = (0..window - 1).into_iter().map(|_| Float::nan()).collect();
+        let nulls_before = std::iter::once(0)
+            .chain((0..series.len()).scan(0, |nulls, idx| {
+                *nulls += series.is_null(idx) as usize;
+                Some(*nulls)
+            }))
+            .collect();
         Rolling {
             window,
             series,
             nans,
+            nulls_before,
         }
     }
 
+    // Whether the window starting at `idx` contains a null, making its aggregation `NaN`
+    fn has_null(&self, idx: usize) -> bool {
+        self.nulls_before[idx + self.window] > self.nulls_before[idx]
+    }
+
     /// Calculate a rolling mean from the current instance.
     pub fn mean(&self) -> Result<Series<f64>, BlackJackError>
     where
@@ -79,6 +93,9 @@ where
             (0..self.series.len() + 1 - self.window)
                 .into_iter()
                 .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
                     let view = arrayview(&self.series.values[idx..idx + self.window]);
                     match view.sum().to_f64() {
                         Some(d) => Ok(d / view.len() as f64),
@@ -104,6 +121,9 @@ where
             (0..self.series.len() + 1 - self.window)
                 .into_iter()
                 .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
                     let view = arrayview(&self.series.values[idx..idx + self.window]);
                     match view.sum().to_f64() {
                         Some(s) => Ok(s),
@@ -130,6 +150,9 @@ where
             (0..self.series.len() + 1 - self.window)
                 .into_iter()
                 .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
                     match funcs::variance(&self.series.values[idx..idx + self.window], ddof) {
                         Some(var) => Ok(var),
                         None => Err(BlackJackError::from(
@@ -157,14 +180,17 @@ where
         vals.extend(
             (0..self.series.len() + 1 - self.window)
                 .into_iter()
-                .map(
-                    |idx| match funcs::std(&self.series.values[idx..idx + self.window], ddof) {
+                .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
+                    match funcs::std(&self.series.values[idx..idx + self.window], ddof) {
                         Some(std) => Ok(std),
                         None => Err(BlackJackError::from(
                             "Failed to calculate standard deviation for window",
                         )),
-                    },
-                )
+                    }
+                })
                 .collect::<Result<Vec<f64>, _>>()?,
         );
         Ok(Series::from_vec(vals))
@@ -184,6 +210,9 @@ where
             (0..self.series.len() + 1 - self.window)
                 .into_par_iter()
                 .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
                     match stats::median(
                         self.series.values[idx..idx + self.window]
                             .iter()
@@ -211,12 +240,15 @@ where
         vals.extend(
             (0..self.series.len() + 1 - self.window)
                 .into_iter()
-                .map(
-                    |idx| match funcs::min(&self.series.values[idx..idx + self.window]) {
+                .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
+                    match funcs::min(&self.series.values[idx..idx + self.window]) {
                         Some(min) => Ok(min.to_f64().unwrap()),
                         None => Err(BlackJackError::from("Failed to calculate min for window")),
-                    },
-                )
+                    }
+                })
                 .collect::<Result<Vec<f64>, _>>()?,
         );
         Ok(Series::from_vec(vals))
@@ -235,14 +267,91 @@ where
         vals.extend(
             (0..self.series.len() + 1 - self.window)
                 .into_iter()
-                .map(
-                    |idx| match funcs::max(&self.series.values[idx..idx + self.window]) {
+                .map(|idx| {
+                    if self.has_null(idx) {
+                        return Ok(f64::NAN);
+                    }
+                    match funcs::max(&self.series.values[idx..idx + self.window]) {
                         Some(max) => Ok(max.to_f64().unwrap()),
                         None => Err(BlackJackError::from("Failed to calculate min for window")),
-                    },
-                )
+                    }
+                })
                 .collect::<Result<Vec<f64>, _>>()?,
         );
         Ok(Series::from_vec(vals))
     }
+
+    /// Calculate the rolling skewness for each window, see [`Series::skew`]
+    pub fn skew(&self, bias: bool) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.apply_window(|window| funcs::skew(window, bias))
+    }
+
+    /// Calculate the rolling excess kurtosis for each window, see [`Series::kurtosis`]
+    pub fn kurtosis(&self, bias: bool) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.apply_window(|window| funcs::kurtosis(window, bias))
+    }
+
+    /// Calculate the rolling standard error of the mean for each window, see [`Series::sem`]
+    pub fn sem(&self, ddof: f64) -> Result<Series<f64>, BlackJackError>
+    where
+        T: Num + ToPrimitive,
+    {
+        self.apply_window(|window| funcs::sem(window, ddof))
+    }
+
+    /// Calculate the rolling mean absolute deviation for each window
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., 3., 2., 8.]);
+    /// let rolled = series.rolling(2).mean_abs_dev().unwrap();
+    ///
+    /// assert!(rolled[0].is_nan());
+    /// assert_eq!(rolled.values[1..], [1., 0.5, 3.]);
+    /// ```
+    pub fn mean_abs_dev(&self) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.apply_window(funcs::mean_abs_dev)
+    }
+
+    /// Calculate the rolling median absolute deviation for each window
+    pub fn median_abs_dev(&self) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.apply_window(funcs::median_abs_dev)
+    }
+
+    // Apply `func` to each full window, preceded by NaNs up to the first; windows
+    // without enough values for `func`, or containing a null or `NaN`, are `NaN`, as for `var`
+    fn apply_window<F>(&self, func: F) -> Result<Series<f64>, BlackJackError>
+    where
+        F: Fn(&[T]) -> Option<f64>,
+    {
+        let mut vals = self.nans.clone();
+        vals.extend(
+            self.series
+                .values
+                .windows(self.window)
+                .enumerate()
+                .map(|(idx, window)| {
+                    if self.has_null(idx) {
+                        f64::NAN
+                    } else {
+                        func(window).unwrap_or(f64::NAN)
+                    }
+                }),
+        );
+        Ok(Series::from_vec(vals))
+    }
 }
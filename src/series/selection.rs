@@ -0,0 +1,150 @@
+//! Membership and conditional selection for `Series`; masks of the elements in a
+//! set of values or a range, and replacing elements where a mask holds or not.
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![1, 5, 8, 12]);
+//!
+//! assert_eq!(series.isin(&[5, 12]).values, vec![false, true, false, true]);
+//!
+//! let mask = series.between(5, 10, Inclusive::Both);
+//! assert_eq!(mask.values, vec![false, true, true, false]);
+//!
+//! assert_eq!(series.where_(&mask, 0).unwrap().values, vec![0, 5, 8, 0]);
+//! assert_eq!(series.mask(&mask, 0).unwrap().values, vec![1, 0, 0, 12]);
+//! ```
+
+use std::collections::HashSet;
+
+use crate::prelude::*;
+use crate::traits::HashKey;
+
+/// Membership and range masks, which are null wherever the element is null, and
+/// replacement by mask, where null elements of the mask count as `false`.
+impl<T> Series<T>
+where
+    T: BlackJackData,
+{
+    /// Mask of the elements equal to any of `values`; `NaN` matches `NaN`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![Some("a".to_string()), None, Some("c".to_string())]);
+    /// let mask = series.isin(&["a".to_string(), "b".to_string()]);
+    ///
+    /// assert_eq!(mask.get(0), Some(&true));
+    /// assert_eq!(mask.get(1), None);
+    /// assert_eq!(mask.get(2), Some(&false));
+    /// ```
+    pub fn isin(&self, values: &[T]) -> Series<bool> {
+        let values = values.iter().map(HashKey).collect::<HashSet<HashKey<T>>>();
+        self.to_mask(|value| values.contains(&HashKey(value)))
+    }
+
+    /// Mask of the elements between `low` and `high`, including either bound as
+    /// given by `inclusive`
+    pub fn between(&self, low: T, high: T, inclusive: Inclusive) -> Series<bool>
+    where
+        T: PartialOrd,
+    {
+        self.to_mask(|value| {
+            let above = match inclusive {
+                Inclusive::Both | Inclusive::Left => *value >= low,
+                Inclusive::Neither | Inclusive::Right => *value > low,
+            };
+            let below = match inclusive {
+                Inclusive::Both | Inclusive::Right => *value <= high,
+                Inclusive::Neither | Inclusive::Left => *value < high,
+            };
+            above && below
+        })
+    }
+
+    /// Keep the elements where `mask` is `true`, replacing the rest by `other`;
+    /// either a scalar or the element at the same position of another series.
+    ///
+    /// Returns a `LengthMismatch` error if the mask, or other series, differs in length.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., -2., 3.]);
+    /// let other = Series::from_options(vec![Some(10.), None, Some(30.)]);
+    ///
+    /// let kept = series.where_(&series.gt(0.).unwrap(), &other).unwrap();
+    /// assert_eq!(kept.get(0), Some(&1.));
+    /// assert_eq!(kept.get(1), None);
+    /// ```
+    pub fn where_<'a, O>(&self, mask: &Series<bool>, other: O) -> Result<Series<T>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: 'a,
+    {
+        self.replace_by_mask(mask, other.into(), false)
+    }
+
+    /// Replace the elements where `mask` is `true` by `other`, keeping the rest;
+    /// the opposite of [`Series::where_`].
+    ///
+    /// Returns a `LengthMismatch` error if the mask, or other series, differs in length.
+    pub fn mask<'a, O>(&self, mask: &Series<bool>, other: O) -> Result<Series<T>, BlackJackError>
+    where
+        O: Into<Operand<'a, T>>,
+        T: 'a,
+    {
+        self.replace_by_mask(mask, other.into(), true)
+    }
+
+    // Boolean mask of `condition` on each element, null where the element is null
+    fn to_mask<F>(&self, condition: F) -> Series<bool>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut mask = Series::from_vec(self.values.iter().map(condition).collect());
+        mask.name = self.name.clone();
+        mask.dtype = Some(DType::BOOL);
+        mask.validity = self.validity.clone();
+        mask
+    }
+
+    // Replace elements by `other` where the mask, counting null as `false`, is `replace_if`
+    fn replace_by_mask(
+        &self,
+        mask: &Series<bool>,
+        other: Operand<'_, T>,
+        replace_if: bool,
+    ) -> Result<Series<T>, BlackJackError> {
+        let other_len = match &other {
+            Operand::Scalar(_) => self.len(),
+            Operand::Series(other) => other.len(),
+        };
+        for len in [mask.len(), other_len].iter() {
+            if *len != self.len() {
+                return Err(BlackJackError::LengthMismatch(format!(
+                    "Source series is of size: {}, and other is of size: {}",
+                    self.len(),
+                    len
+                )));
+            }
+        }
+
+        let values = (0..self.len())
+            .map(|idx| {
+                let holds = !mask.is_null(idx) && mask.values[idx];
+                if holds != replace_if {
+                    return self.get(idx).cloned();
+                }
+                match &other {
+                    Operand::Scalar(scalar) => Some(scalar.clone()),
+                    Operand::Series(other) => other.get(idx).cloned(),
+                }
+            })
+            .collect();
+        Ok(self.with_options(values))
+    }
+}
@@ -1,6 +1,7 @@
 //! Series `groupby` functionality
 
 use std::iter::Sum;
+use std::ops::{Add, Mul};
 
 use crate::prelude::*;
 use num::*;
@@ -10,15 +11,44 @@ use num::*;
 #[derive(Clone)]
 pub struct SeriesGroupBy<T: BlackJackData> {
     groups: Vec<Series<T>>,
+    // Positions of each group's elements within the grouped series, and its length
+    positions: Vec<Vec<usize>>,
+    len: usize,
 }
 
 impl<T> SeriesGroupBy<T>
 where
     T: BlackJackData,
 {
-    /// Create a new [`SeriesGroupBy`] from a `Vec<Series>`
+    /// Create a new [`SeriesGroupBy`] from a `Vec<Series>`, where the groups are
+    /// taken to be consecutive elements of the grouped series.
     pub fn new(groups: Vec<Series<T>>) -> Self {
-        SeriesGroupBy { groups }
+        let mut len = 0;
+        let positions = groups
+            .iter()
+            .map(|group| {
+                len += group.len();
+                (len - group.len()..len).collect()
+            })
+            .collect();
+        SeriesGroupBy {
+            groups,
+            positions,
+            len,
+        }
+    }
+
+    // Create from groups along with the positions of their elements in a series of `len`
+    pub(crate) fn from_positions(
+        groups: Vec<Series<T>>,
+        positions: Vec<Vec<usize>>,
+        len: usize,
+    ) -> Self {
+        SeriesGroupBy {
+            groups,
+            positions,
+            len,
+        }
     }
 
     /// Apply an **aggregation** function to each [`Series`]
@@ -111,4 +141,148 @@ where
         }
         Ok(Series::from_vec(results))
     }
+
+    /// Apply a `skew` aggregation to each [`Series`] group, see [`Series::skew`]
+    pub fn skew(&self, bias: bool) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.aggregate(|group| group.skew(bias))
+    }
+
+    /// Apply a `kurtosis` aggregation to each [`Series`] group, see [`Series::kurtosis`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 5, 2, 5, 3, 5, 4, 6]);
+    /// let keys   = Series::from_vec(vec![0, 1, 0, 1, 0, 1, 0, 1]);
+    ///
+    /// let kurtosis = series.groupby(&keys).kurtosis(false).unwrap();
+    /// assert_eq!(kurtosis.round(6).values, vec![-1.2, 4.]);
+    /// ```
+    pub fn kurtosis(&self, bias: bool) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.aggregate(|group| group.kurtosis(bias))
+    }
+
+    /// Apply a `sem` aggregation to each [`Series`] group, see [`Series::sem`]
+    pub fn sem(&self, ddof: f64) -> Result<Series<f64>, BlackJackError>
+    where
+        T: Num + ToPrimitive,
+    {
+        self.aggregate(|group| group.sem(ddof))
+    }
+
+    /// Apply a `mean_abs_dev` aggregation to each [`Series`] group, see [`Series::mean_abs_dev`]
+    pub fn mean_abs_dev(&self) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.aggregate(Series::mean_abs_dev)
+    }
+
+    /// Apply a `median_abs_dev` aggregation to each [`Series`] group, see
+    /// [`Series::median_abs_dev`]
+    pub fn median_abs_dev(&self) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        self.aggregate(Series::median_abs_dev)
+    }
+
+    // Series of `func` of each group, or the first error
+    fn aggregate<F>(&self, func: F) -> Result<Series<f64>, BlackJackError>
+    where
+        F: Fn(&Series<T>) -> Result<f64, BlackJackError>,
+    {
+        let results = self
+            .groups
+            .iter()
+            .map(func)
+            .collect::<Result<Vec<f64>, _>>()?;
+        Ok(Series::from_vec(results))
+    }
+
+    /// Apply a function to each group yielding a series of the same length, and combine
+    /// the results into a series aligned with the grouped series. Elements which aren't
+    /// in any group, ie. due to a missing key, are null.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4]);
+    /// let keys   = Series::from_vec(vec![0, 1, 0, 1]);
+    ///
+    /// let ranks = series.groupby(&keys).transform(|group| group.rank(RankMethod::First, false));
+    /// assert_eq!(ranks.values, vec![1., 1., 2., 2.]);
+    /// ```
+    pub fn transform<O, F>(&self, func: F) -> Series<O>
+    where
+        O: BlackJackData,
+        F: Fn(&Series<T>) -> Series<O>,
+    {
+        let mut values: Vec<Option<O>> = vec![None; self.len];
+        for (group, positions) in self.groups.iter().zip(&self.positions) {
+            let result = func(group);
+            for (idx, position) in positions.iter().enumerate() {
+                values[*position] = result.get(idx).cloned();
+            }
+        }
+        Series::from_options(values)
+    }
+
+    /// Running sum within each group, see [`Series::cumsum`]
+    pub fn cumsum(&self) -> Series<T>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.transform(Series::cumsum)
+    }
+
+    /// Running product within each group, see [`Series::cumprod`]
+    pub fn cumprod(&self) -> Series<T>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        self.transform(Series::cumprod)
+    }
+
+    /// Running minimum within each group, see [`Series::cummin`]
+    pub fn cummin(&self) -> Series<T>
+    where
+        T: PartialOrd,
+    {
+        self.transform(Series::cummin)
+    }
+
+    /// Running maximum within each group, see [`Series::cummax`]
+    pub fn cummax(&self) -> Series<T>
+    where
+        T: PartialOrd,
+    {
+        self.transform(Series::cummax)
+    }
+
+    /// Number each non-missing element within its group, counting from zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![5, 6, 7, 8]);
+    /// let keys   = Series::from_vec(vec![0, 1, 0, 0]);
+    ///
+    /// assert_eq!(series.groupby(&keys).cumcount().values, vec![0, 0, 1, 2]);
+    /// ```
+    pub fn cumcount(&self) -> Series<i64> {
+        self.transform(Series::cumcount)
+    }
 }
@@ -0,0 +1,252 @@
+//! Sorting of `Series`, either by value or by computing the sorting permutation, and ranking
+//!
+//! ## Example
+//! ```
+//! use blackjack::prelude::*;
+//!
+//! let series = Series::from_vec(vec![3., f64::NAN, 1., 2.]);
+//!
+//! let sorted = series.sort_values(true);
+//! assert_eq!(sorted.values[..3], [1., 2., 3.]);
+//! assert!(sorted[3].is_nan());
+//!
+//! let options = SortOptions::new().ascending(false).na_position(NaPosition::First);
+//! assert_eq!(series.argsort_with(&options), vec![1, 0, 3, 2]);
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::prelude::*;
+
+/// Options for [`Series::sort_values_with`] and [`Series::argsort_with`]
+///
+/// Defaults to an ascending, unstable sort placing missing values last.
+#[derive(Debug, Clone)]
+pub struct SortOptions {
+    ascending: bool,
+    stable: bool,
+    na_position: NaPosition,
+}
+
+impl Default for SortOptions {
+    fn default() -> Self {
+        SortOptions {
+            ascending: true,
+            stable: false,
+            na_position: NaPosition::Last,
+        }
+    }
+}
+
+impl SortOptions {
+    /// Create a new instance of `SortOptions` with the defaults
+    pub fn new() -> Self {
+        SortOptions::default()
+    }
+
+    /// Sort in ascending or descending order, default is ascending
+    pub fn ascending(self, yes: bool) -> Self {
+        let mut opts = self;
+        opts.ascending = yes;
+        opts
+    }
+
+    /// Keep equal elements in their original order, at some cost to speed
+    pub fn stable(self, yes: bool) -> Self {
+        let mut opts = self;
+        opts.stable = yes;
+        opts
+    }
+
+    /// Place missing values, nulls and `NaN`, either first or last regardless of order
+    pub fn na_position(self, na_position: NaPosition) -> Self {
+        let mut opts = self;
+        opts.na_position = na_position;
+        opts
+    }
+}
+
+impl<T> Series<T>
+where
+    T: BlackJackData + PartialOrd,
+{
+    /// Sort the series by value with the default [`SortOptions`], keeping the name and dtype
+    pub fn sort_values(&self, ascending: bool) -> Series<T> {
+        self.sort_values_with(&SortOptions::new().ascending(ascending))
+    }
+
+    /// Sort the series by value, keeping the name and dtype
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_options(vec![Some(2), None, Some(1)]);
+    /// let sorted = series.sort_values_with(&SortOptions::new().na_position(NaPosition::First));
+    ///
+    /// assert_eq!(sorted.get(0), None);
+    /// assert_eq!(sorted.values[1..], [1, 2]);
+    /// ```
+    pub fn sort_values_with(&self, options: &SortOptions) -> Series<T> {
+        self.take(&self.argsort_with(options))
+    }
+
+    /// Positions which would sort the series in ascending order with the default [`SortOptions`]
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["b".to_string(), "c".to_string(), "a".to_string()]);
+    /// assert_eq!(series.argsort(), vec![2, 0, 1]);
+    /// ```
+    pub fn argsort(&self) -> Vec<usize> {
+        self.argsort_with(&SortOptions::new())
+    }
+
+    /// Positions which would sort the series, ie. the positions to pass to [`Series::take`]
+    pub fn argsort_with(&self, options: &SortOptions) -> Vec<usize> {
+        let na = self.isna().collect::<Vec<bool>>();
+        let (mut positions, missing): (Vec<usize>, Vec<usize>) =
+            (0..self.len()).partition(|idx| !na[*idx]);
+
+        let compare = |a: &usize, b: &usize| {
+            let ordering = compare_values(&self.values[*a], &self.values[*b]);
+            if options.ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        };
+        if options.stable {
+            positions.sort_by(compare);
+        } else {
+            positions.sort_unstable_by(compare);
+        }
+
+        match options.na_position {
+            NaPosition::First => missing.into_iter().chain(positions).collect(),
+            NaPosition::Last => positions.into_iter().chain(missing).collect(),
+        }
+    }
+
+    /// The `k` largest values in descending order, alongside their positions; ties
+    /// keep the earlier position first and missing values are skipped.
+    ///
+    /// Only the `k` values selected are sorted, rather than the whole series.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![3, 9, 1, 9, 4]);
+    ///
+    /// let (values, positions) = series.nlargest(3);
+    /// assert_eq!(values.values, vec![9, 9, 4]);
+    /// assert_eq!(positions, vec![1, 3, 4]);
+    ///
+    /// let (values, positions) = series.nsmallest(2);
+    /// assert_eq!(values.values, vec![1, 3]);
+    /// assert_eq!(positions, vec![2, 0]);
+    /// ```
+    pub fn nlargest(&self, k: usize) -> (Series<T>, Vec<usize>) {
+        let positions = self.select_positions(k, false);
+        (self.take(&positions), positions)
+    }
+
+    /// The `k` smallest values in ascending order, alongside their positions; ties
+    /// keep the earlier position first and missing values are skipped.
+    pub fn nsmallest(&self, k: usize) -> (Series<T>, Vec<usize>) {
+        let positions = self.select_positions(k, true);
+        (self.take(&positions), positions)
+    }
+
+    // Positions of the first `k` values in sorted order, partially selecting them
+    // before sorting only those
+    pub(crate) fn select_positions(&self, k: usize, ascending: bool) -> Vec<usize> {
+        let na = self.isna().collect::<Vec<bool>>();
+        let mut positions = (0..self.len())
+            .filter(|idx| !na[*idx])
+            .collect::<Vec<usize>>();
+
+        let compare = |a: &usize, b: &usize| {
+            let ordering = compare_values(&self.values[*a], &self.values[*b]);
+            let ordering = if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            };
+            ordering.then(a.cmp(b))
+        };
+        if k < positions.len() {
+            positions.select_nth_unstable_by(k, compare);
+            positions.truncate(k);
+        }
+        positions.sort_unstable_by(compare);
+        positions
+    }
+
+    /// Rank the values from 1 upwards in ascending order, breaking ties by `method`.
+    /// With `pct` the ranks are divided by the highest possible rank, so they fall
+    /// within `(0, 1]`. Missing values are null in the result.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![10, 30, 20, 30]);
+    ///
+    /// assert_eq!(series.rank(RankMethod::Average, false).values, vec![1.0, 3.5, 2.0, 3.5]);
+    /// assert_eq!(series.rank(RankMethod::Min, false).values, vec![1.0, 3.0, 2.0, 3.0]);
+    /// assert_eq!(series.rank(RankMethod::Max, false).values, vec![1.0, 4.0, 2.0, 4.0]);
+    /// assert_eq!(series.rank(RankMethod::First, false).values, vec![1.0, 3.0, 2.0, 4.0]);
+    /// assert_eq!(series.rank(RankMethod::Dense, true).values, vec![1. / 3., 1.0, 2. / 3., 1.0]);
+    /// ```
+    pub fn rank(&self, method: RankMethod, pct: bool) -> Series<f64> {
+        let positions = self.argsort_with(&SortOptions::new().stable(true));
+        let count = self.count();
+        let mut ranks: Vec<Option<f64>> = vec![None; self.len()];
+
+        // Walk each group of equal values, which are adjacent once sorted
+        let (mut start, mut dense) = (0, 0);
+        while start < count {
+            let mut end = start + 1;
+            while end < count
+                && compare_values(&self.values[positions[start]], &self.values[positions[end]])
+                    == Ordering::Equal
+            {
+                end += 1;
+            }
+            dense += 1;
+            for (offset, idx) in positions[start..end].iter().enumerate() {
+                let rank = match method {
+                    RankMethod::Average => (start + end + 1) as f64 / 2.,
+                    RankMethod::Min => (start + 1) as f64,
+                    RankMethod::Max => end as f64,
+                    RankMethod::Dense => dense as f64,
+                    RankMethod::First => (start + offset + 1) as f64,
+                };
+                ranks[*idx] = Some(rank);
+            }
+            start = end;
+        }
+
+        if pct {
+            let highest = if method == RankMethod::Dense {
+                dense
+            } else {
+                count
+            } as f64;
+            ranks.iter_mut().flatten().for_each(|rank| *rank /= highest);
+        }
+
+        let mut series = Series::from_options(ranks);
+        series.name = self.name.clone();
+        series
+    }
+}
+
+/// Total ordering over partially ordered values, treating incomparable values as equal
+pub(crate) fn compare_values<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
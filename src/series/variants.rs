@@ -1,5 +1,9 @@
 //! Variations of `Series` and various helper objects
 
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{NumCast, ToPrimitive};
+
 use crate::prelude::*;
 
 /// Enum for holding valid Series types
@@ -12,12 +16,33 @@ pub enum GenericSeriesContainer {
     I32(Series<i32>),
     /// Hold `f32` type series
     F32(Series<f32>),
+    /// Hold `i16` type series
+    I16(Series<i16>),
+    /// Hold `i8` type series
+    I8(Series<i8>),
+    /// Hold `u64` type series
+    U64(Series<u64>),
+    /// Hold `u32` type series
+    U32(Series<u32>),
+    /// Hold `u16` type series
+    U16(Series<u16>),
+    /// Hold `u8` type series
+    U8(Series<u8>),
     /// Hold `String` type series
     STRING(Series<String>),
+    /// Hold `bool` type series
+    BOOL(Series<bool>),
+    /// Hold `DateTime` type series
+    DATETIME(Series<DateTime>),
+    /// Hold `Duration` type series
+    DURATION(Series<Duration>),
+    /// Hold a [`Categorical`] column
+    CATEGORICAL(Categorical),
 }
 
 impl GenericSeriesContainer {
-    /// Convert a `GenericSeriesContainer` into a `Vec<String>`
+    /// Convert a `GenericSeriesContainer` into a `Vec<String>`, where null
+    /// elements become empty strings.
     pub fn into_string_vec(self) -> Vec<String> {
         // TODO: `.unwrap()` is pretty safe here, but should avoid it anyhow.
         match self {
@@ -25,11 +50,112 @@ impl GenericSeriesContainer {
             GenericSeriesContainer::F64(series) => series.into_type::<String>().unwrap().into_vec(),
             GenericSeriesContainer::I32(series) => series.into_type::<String>().unwrap().into_vec(),
             GenericSeriesContainer::F32(series) => series.into_type::<String>().unwrap().into_vec(),
-            GenericSeriesContainer::STRING(series) => series.into_vec(),
+            GenericSeriesContainer::I16(series) => series.into_type::<String>().unwrap().into_vec(),
+            GenericSeriesContainer::I8(series) => series.into_type::<String>().unwrap().into_vec(),
+            GenericSeriesContainer::U64(series) => series.into_type::<String>().unwrap().into_vec(),
+            GenericSeriesContainer::U32(series) => series.into_type::<String>().unwrap().into_vec(),
+            GenericSeriesContainer::U16(series) => series.into_type::<String>().unwrap().into_vec(),
+            GenericSeriesContainer::U8(series) => series.into_type::<String>().unwrap().into_vec(),
+            GenericSeriesContainer::STRING(series) => {
+                series.into_type::<String>().unwrap().into_vec()
+            }
+            GenericSeriesContainer::BOOL(series) => {
+                series.into_type::<String>().unwrap().into_vec()
+            }
+            GenericSeriesContainer::DATETIME(series) => {
+                series.into_type::<String>().unwrap().into_vec()
+            }
+            GenericSeriesContainer::DURATION(series) => {
+                series.into_type::<String>().unwrap().into_vec()
+            }
+            GenericSeriesContainer::CATEGORICAL(categorical) => categorical.to_series().into_vec(),
+        }
+    }
+}
+
+impl GenericSeriesContainer {
+    /// The [`DType`] of the contained series
+    pub fn dtype(&self) -> DType {
+        match self {
+            GenericSeriesContainer::I64(_) => DType::I64,
+            GenericSeriesContainer::F64(_) => DType::F64,
+            GenericSeriesContainer::I32(_) => DType::I32,
+            GenericSeriesContainer::F32(_) => DType::F32,
+            GenericSeriesContainer::I16(_) => DType::I16,
+            GenericSeriesContainer::I8(_) => DType::I8,
+            GenericSeriesContainer::U64(_) => DType::U64,
+            GenericSeriesContainer::U32(_) => DType::U32,
+            GenericSeriesContainer::U16(_) => DType::U16,
+            GenericSeriesContainer::U8(_) => DType::U8,
+            GenericSeriesContainer::STRING(_) => DType::STRING,
+            GenericSeriesContainer::BOOL(_) => DType::BOOL,
+            GenericSeriesContainer::DATETIME(_) => DType::DATETIME,
+            GenericSeriesContainer::DURATION(_) => DType::DURATION,
+            GenericSeriesContainer::CATEGORICAL(_) => DType::CATEGORICAL,
+        }
+    }
+
+    // Cast a numeric series to a numeric `dtype`, `None` if either isn't numeric
+    fn cast(self, dtype: &DType) -> Option<GenericSeriesContainer> {
+        macro_rules! cast_to {
+            ($series:expr) => {{
+                let series = $series;
+                match dtype {
+                    DType::I64 => GenericSeriesContainer::I64(cast_series(series)),
+                    DType::F64 => GenericSeriesContainer::F64(cast_series(series)),
+                    DType::I32 => GenericSeriesContainer::I32(cast_series(series)),
+                    DType::F32 => GenericSeriesContainer::F32(cast_series(series)),
+                    DType::I16 => GenericSeriesContainer::I16(cast_series(series)),
+                    DType::I8 => GenericSeriesContainer::I8(cast_series(series)),
+                    DType::U64 => GenericSeriesContainer::U64(cast_series(series)),
+                    DType::U32 => GenericSeriesContainer::U32(cast_series(series)),
+                    DType::U16 => GenericSeriesContainer::U16(cast_series(series)),
+                    DType::U8 => GenericSeriesContainer::U8(cast_series(series)),
+                    _ => return None,
+                }
+            }};
         }
+
+        let container = match self {
+            GenericSeriesContainer::I64(series) => cast_to!(series),
+            GenericSeriesContainer::F64(series) => cast_to!(series),
+            GenericSeriesContainer::I32(series) => cast_to!(series),
+            GenericSeriesContainer::F32(series) => cast_to!(series),
+            GenericSeriesContainer::I16(series) => cast_to!(series),
+            GenericSeriesContainer::I8(series) => cast_to!(series),
+            GenericSeriesContainer::U64(series) => cast_to!(series),
+            GenericSeriesContainer::U32(series) => cast_to!(series),
+            GenericSeriesContainer::U16(series) => cast_to!(series),
+            GenericSeriesContainer::U8(series) => cast_to!(series),
+            _ => return None,
+        };
+        Some(container)
     }
 }
 
+// Support `container + other_container`, promoting both to a common dtype
+impl_container_op!(Add, add, +);
+impl_container_op!(Sub, sub, -);
+impl_container_op!(Mul, mul, *);
+impl_container_op!(Div, div, /);
+
+// Cast the elements of a numeric series, keeping the name and nulls
+fn cast_series<A, B>(series: Series<A>) -> Series<B>
+where
+    A: BlackJackData + ToPrimitive + Copy,
+    B: BlackJackData + NumCast,
+{
+    let values = series
+        .values
+        .iter()
+        .map(|value| B::from(*value).unwrap())
+        .collect();
+    let mut cast = Series::from_vec(values);
+    cast.name = series.name;
+    cast.validity = series.validity;
+    cast
+}
+
 /// Serialized version of `Series<T>`, enabling storage inside a homogeneous container
 /// where metadata is stored and data is stored in byte/compressed format.
 #[derive(Debug, Clone)]
@@ -51,3 +177,13 @@ impl<T: BlackJackData> From<&Series<T>> for SeriesMeta {
         }
     }
 }
+
+impl From<&Categorical> for SeriesMeta {
+    fn from(categorical: &Categorical) -> SeriesMeta {
+        SeriesMeta {
+            name: categorical.name().unwrap(),
+            len: categorical.len(),
+            dtype: DType::CATEGORICAL,
+        }
+    }
+}
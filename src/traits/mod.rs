@@ -1,38 +1,187 @@
 //! Traits to be used throughout the crate
 
 use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
 
 use serde::Serialize;
 
 use crate::prelude::*;
 
 /// Trait dictates the supported primitives for use in [`Series`] structs.
-pub trait BlackJackData: Serialize + Debug + ToString + Clone + Send + Display {
+pub trait BlackJackData: Serialize + Debug + ToString + Clone + Send + Display + Default {
     /// Return the current [`DType`] for this type.
     fn dtype(&self) -> DType;
+
+    /// Determine if this value is considered missing by itself, regardless of
+    /// a [`Series`] validity; ie. `NaN` for floats.
+    fn is_na(&self) -> bool {
+        false
+    }
+
+    /// Feed this value into a hasher, such that values which are [`BlackJackData::eq_value`]
+    /// hash equally; used by hash based operations such as [`Series::value_counts`].
+    ///
+    /// Defaults to hashing the value's string representation.
+    fn hash_value<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state)
+    }
+
+    /// Equality for hash based operations, where unlike `==` every `NaN` is the same value.
+    ///
+    /// Defaults to comparing the values' string representations.
+    fn eq_value(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
 }
 impl BlackJackData for f64 {
     fn dtype(&self) -> DType {
         DType::F64
     }
+    fn is_na(&self) -> bool {
+        self.is_nan()
+    }
+    fn hash_value<H: Hasher>(&self, state: &mut H) {
+        // Hash every NaN alike, and -0.0 as 0.0 which it equals
+        let value = if self.is_nan() {
+            f64::NAN
+        } else if *self == 0.0 {
+            0.0
+        } else {
+            *self
+        };
+        value.to_bits().hash(state)
+    }
+    fn eq_value(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
 }
 impl BlackJackData for i64 {
     fn dtype(&self) -> DType {
         DType::I64
     }
+    impl_hash_by_value!();
 }
 impl BlackJackData for f32 {
     fn dtype(&self) -> DType {
         DType::F32
     }
+    fn is_na(&self) -> bool {
+        self.is_nan()
+    }
+    fn hash_value<H: Hasher>(&self, state: &mut H) {
+        // Hash every NaN alike, and -0.0 as 0.0 which it equals
+        let value = if self.is_nan() {
+            f32::NAN
+        } else if *self == 0.0 {
+            0.0
+        } else {
+            *self
+        };
+        value.to_bits().hash(state)
+    }
+    fn eq_value(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
 }
 impl BlackJackData for i32 {
     fn dtype(&self) -> DType {
         DType::I32
     }
+    impl_hash_by_value!();
+}
+impl BlackJackData for i16 {
+    fn dtype(&self) -> DType {
+        DType::I16
+    }
+    impl_hash_by_value!();
+}
+impl BlackJackData for i8 {
+    fn dtype(&self) -> DType {
+        DType::I8
+    }
+    impl_hash_by_value!();
+}
+impl BlackJackData for u64 {
+    fn dtype(&self) -> DType {
+        DType::U64
+    }
+    impl_hash_by_value!();
+}
+impl BlackJackData for u32 {
+    fn dtype(&self) -> DType {
+        DType::U32
+    }
+    impl_hash_by_value!();
+}
+impl BlackJackData for u16 {
+    fn dtype(&self) -> DType {
+        DType::U16
+    }
+    impl_hash_by_value!();
+}
+impl BlackJackData for u8 {
+    fn dtype(&self) -> DType {
+        DType::U8
+    }
+    impl_hash_by_value!();
 }
 impl BlackJackData for String {
     fn dtype(&self) -> DType {
         DType::STRING
     }
+    impl_hash_by_value!();
+}
+impl BlackJackData for bool {
+    fn dtype(&self) -> DType {
+        DType::BOOL
+    }
+    impl_hash_by_value!();
+}
+
+/// Integer division with explicit overflow behaviour, completing the saturating and
+/// wrapping operations of `num`; the only overflow is `MIN / -1` of signed dtypes.
+///
+/// Both panic if `v` is zero, as the primitive methods do.
+pub trait OverflowDiv: Sized {
+    /// `self / v`, clamped to the bounds of the dtype
+    fn saturating_div(&self, v: &Self) -> Self;
+
+    /// `self / v`, wrapping around the bounds of the dtype
+    fn wrapping_div(&self, v: &Self) -> Self;
+}
+
+macro_rules! impl_overflow_div {
+    ($($primitive:ty),*) => {
+        $(
+            impl OverflowDiv for $primitive {
+                fn saturating_div(&self, v: &Self) -> Self {
+                    <$primitive>::saturating_div(*self, *v)
+                }
+
+                fn wrapping_div(&self, v: &Self) -> Self {
+                    <$primitive>::wrapping_div(*self, *v)
+                }
+            }
+        )*
+    };
+}
+
+impl_overflow_div!(i64, i32, i16, i8, u64, u32, u16, u8);
+
+/// Wrapper to use [`BlackJackData`] as a key of a `HashMap` or `HashSet`, hashing and
+/// comparing by [`BlackJackData::hash_value`] and [`BlackJackData::eq_value`].
+pub(crate) struct HashKey<'a, T: BlackJackData>(pub &'a T);
+
+impl<'a, T: BlackJackData> Hash for HashKey<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_value(state)
+    }
+}
+
+impl<'a, T: BlackJackData> PartialEq for HashKey<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_value(other.0)
+    }
 }
+
+impl<'a, T: BlackJackData> Eq for HashKey<'a, T> {}
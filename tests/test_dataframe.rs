@@ -1,6 +1,8 @@
 extern crate blackjack;
 extern crate tempfile;
 
+use std::collections::HashMap;
+
 use tempfile::tempdir;
 
 use blackjack::prelude::*;
@@ -113,6 +115,126 @@ fn test_read_write_basic_csv() {
     }
 }
 
+#[test]
+fn test_read_write_missing_csv() {
+    let path = format!("{}/tests/data/missing_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    let df = Reader::new(&path).read().unwrap();
+
+    // Empty fields become nulls rather than forcing the column to `String`
+    let col1: &Series<i32> = df.get_column("col1").unwrap();
+    assert_eq!(col1.isna().collect::<Vec<bool>>(), vec![false, true, false]);
+    assert_eq!(col1.sum(), 4);
+
+    let col2: &Series<f32> = df.get_column("col2").unwrap();
+    assert_eq!(col2.count(), 2);
+    assert_eq!(col2.mean().unwrap(), 2.0);
+
+    let col3: &Series<String> = df.get_column("col3").unwrap();
+    assert_eq!(col3.get(1), None);
+
+    // Nulls are written out as empty fields
+    let tdir = tempdir().unwrap();
+    let out_path = tdir.path().join("out.csv");
+    let out_path_str = out_path.to_str().unwrap();
+    Writer::new(&out_path_str).write(df).unwrap();
+    let new_df = Reader::new(&out_path_str).read().unwrap();
+    let col1: &Series<i32> = new_df.get_column("col1").unwrap();
+    assert_eq!(col1.count(), 2);
+}
+
+#[test]
+fn test_read_bool_csv_and_filter_by_mask() {
+    let path = format!("{}/tests/data/bool_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    let mut df = Reader::new(&path).read().unwrap();
+
+    // `true` / `false` columns are inferred as boolean
+    let mask: Series<bool> = df.get_column::<bool>("flag").unwrap().clone();
+    assert_eq!(mask.null_count(), 1);
+
+    df.filter_by_mask(&mask).unwrap();
+    assert_eq!(df.len(), 2);
+
+    let ids: &Series<i32> = df.get_column("id").unwrap();
+    assert_eq!(ids.values, vec![1, 4]);
+
+    let rows = df.iter_rows().collect::<Vec<Row>>();
+    assert!(rows[0]["flag"] == Datum::BOOL(&true));
+
+    // Mask must be of the same length
+    assert!(df.filter_by_mask(&Series::from_vec(vec![true])).is_err());
+}
+
+#[test]
+fn test_read_categorical_csv() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let mut df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    // Only the low cardinality string column is dictionary encoded
+    let city = df.get_categorical_column("city").unwrap();
+    assert_eq!(
+        city.categories(),
+        &["Oslo".to_string(), "Bergen".to_string()]
+    );
+    assert_eq!(city.null_count(), 1);
+    assert!(df.get_categorical_column("name").is_none());
+    assert!(df.get_column::<String>("name").is_some());
+
+    let ids: Series<i32> = df.get_column::<i32>("id").unwrap().clone();
+    let sums = city.groupby(&ids).sum();
+    assert_eq!(sums.values, vec![9, 8]);
+
+    df.filter_by_mask(&ids.gt(2).unwrap()).unwrap();
+    let rows = df.iter_rows().collect::<Vec<Row>>();
+    assert!(rows[0]["city"] == Datum::STR(&"Oslo".to_string()));
+    assert!(rows[1]["city"] == Datum::STR(&"".to_string()));
+
+    let tdir = tempdir().unwrap();
+    let out_path = tdir.path().join("out.csv");
+    let out_path_str = out_path.to_str().unwrap();
+    Writer::new(&out_path_str).write(df).unwrap();
+
+    let df = Reader::new(&out_path_str).read().unwrap();
+    let city: &Series<String> = df.get_column("city").unwrap();
+    assert_eq!(city.get(0), Some(&"Oslo".to_string()));
+    assert_eq!(city.get(1), None);
+}
+
+#[test]
+fn test_read_narrow_int_csv_with_schema() {
+    let path = format!(
+        "{}/tests/data/narrow_int_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let df = Reader::new(&path)
+        .dtype("id", DType::U64)
+        .dtype("flag", DType::U8)
+        .dtype("delta", DType::I8)
+        .read()
+        .unwrap();
+
+    let id: &Series<u64> = df.get_column("id").unwrap();
+    assert_eq!(id.dtype(), Some(DType::U64));
+    assert_eq!(id.max().unwrap(), u64::MAX);
+
+    let flag: &Series<u8> = df.get_column("flag").unwrap();
+    assert_eq!(flag.sum(), 2);
+
+    let delta: &Series<i8> = df.get_column("delta").unwrap();
+    assert_eq!(delta.null_count(), 1);
+    assert_eq!(delta.min().unwrap(), -5);
+
+    let rows = df.iter_rows().collect::<Vec<Row>>();
+    assert!(rows[1]["id"] == Datum::U64(&42));
+    assert!(rows[2]["delta"] == Datum::I8(&120));
+
+    // Fields which don't fit the requested dtype are an error
+    assert!(Reader::new(&path).dtype("delta", DType::U8).read().is_err());
+    assert!(Reader::new(&path).dtype("id", DType::I64).read().is_err());
+}
+
 #[test]
 fn test_read_gzipped_basic_csv() {
     let path = format!("{}/tests/data/basic_csv.csv.gz", env!("CARGO_MANIFEST_DIR"));
@@ -172,3 +294,298 @@ fn test_get_column_by_name() {
         .expect("Unable to find column named 'test-series'");
     assert_eq!(series_ref, &series_clone);
 }
+
+#[test]
+fn test_sort_values() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let mut df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    let mut score = Series::from_options(vec![
+        Some(1.5),
+        Some(2.0),
+        None,
+        Some(0.5),
+        Some(1.5),
+        Some(3.0),
+    ]);
+    score.set_name("score");
+    df.add_column(score).unwrap();
+
+    // Categories sort by value, missing values go last and ties keep their order
+    df.sort_values(&["city", "score"], &[true, false]).unwrap();
+
+    let ids: &Series<i32> = df.get_column("id").unwrap();
+    assert_eq!(ids.values, vec![6, 2, 1, 5, 3, 4]);
+
+    let score: &Series<f64> = df.get_column("score").unwrap();
+    assert_eq!(score.get(4), None);
+
+    let name: &Series<String> = df.get_column("name").unwrap();
+    assert_eq!(name[0], "Fay");
+
+    let rows = df.iter_rows().collect::<Vec<Row>>();
+    assert!(rows[5]["city"] == Datum::STR(&"".to_string()));
+
+    df.sort_values(&["id"], &[true]).unwrap();
+    let ids: &Series<i32> = df.get_column("id").unwrap();
+    assert_eq!(ids.values, vec![1, 2, 3, 4, 5, 6]);
+
+    assert!(df.sort_values(&["id"], &[true, false]).is_err());
+    assert!(df.sort_values(&["missing"], &[true]).is_err());
+}
+
+#[test]
+fn test_shift_diff_pct_change() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    let shifted = df.shift(1);
+    assert_eq!(shifted.n_columns(), df.n_columns());
+    let city = shifted.get_categorical_column("city").unwrap();
+    assert_eq!(city.get(0), None);
+    assert_eq!(
+        city.get(1),
+        df.get_categorical_column("city").unwrap().get(0)
+    );
+    let ids: &Series<i32> = shifted.get_column("id").unwrap();
+    assert_eq!(ids.get(1), Some(&1));
+
+    // Only numeric columns are kept, as floats
+    let diff = df.diff(1);
+    assert_eq!(diff.n_columns(), 1);
+    let ids: &Series<f64> = diff.get_column("id").unwrap();
+    assert_eq!(ids.get(0), None);
+    assert_eq!(ids.values[1..], [1., 1., 1., 1., 1.]);
+
+    let pct = df.pct_change(2);
+    let ids: &Series<f64> = pct.get_column("id").unwrap();
+    assert_eq!(ids.get(2), Some(&2.0));
+}
+
+#[test]
+fn test_fillna() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let mut df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    let mut score = Series::from_options(vec![Some(1.), None, Some(3.), None, None, Some(6.)]);
+    score.set_name("score");
+    df.add_column(score).unwrap();
+
+    let unknown = "Unknown".to_string();
+    let mut values = HashMap::new();
+    values.insert("city", Datum::STR(&unknown));
+    values.insert("score", Datum::F64(&0.));
+    df.fillna(&values).unwrap();
+
+    let city = df.get_categorical_column("city").unwrap();
+    assert_eq!(city.null_count(), 0);
+    assert_eq!(city.categories().last(), Some(&"Unknown".to_string()));
+    let score: &Series<f64> = df.get_column("score").unwrap();
+    assert_eq!(score.values, vec![1., 0., 3., 0., 0., 6.]);
+
+    // Nothing is filled when any value is invalid
+    let mut values = HashMap::new();
+    values.insert("id", Datum::F64(&0.));
+    assert!(df.fillna(&values).is_err());
+    let mut values = HashMap::new();
+    values.insert("missing", Datum::F64(&0.));
+    assert!(df.fillna(&values).is_err());
+}
+
+#[test]
+fn test_set_index() {
+    let mut df = DataFrame::new();
+    df.add_column(Series::from_vec(vec![1.5, 2.5, 3.5]))
+        .unwrap();
+    df.set_index(Series::from_vec(vec![100, 200, 300])).unwrap();
+
+    // Adding columns keeps the labels, while reordering rows moves them
+    df.add_column(Series::from_vec(vec![3, 1, 2])).unwrap();
+    assert_eq!(df.index().values, vec![100, 200, 300]);
+    df.sort_values(&["col_1"], &[true]).unwrap();
+    assert_eq!(df.index().values, vec![200, 300, 100]);
+
+    assert!(df.set_index(Series::from_vec(vec![1, 2])).is_err());
+    assert_eq!(df.index().len(), 3);
+}
+
+#[test]
+fn test_describe() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let mut df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+    let mut flags = Series::from_vec(vec![true, false, true, true, false, true]);
+    flags.set_name("flags");
+    df.add_column(flags).unwrap();
+
+    let summary = df.describe();
+    assert_eq!(summary.n_columns(), 3);
+    assert_eq!(summary.len(), 11);
+    assert_eq!(
+        summary.index().values[..4],
+        ["count", "unique", "top", "freq"]
+    );
+    assert!(summary.get_column::<bool>("flags").is_none());
+
+    let ids: &Series<f64> = summary.get_column("id").unwrap();
+    assert_eq!(ids.get(0), Some(&6.));
+    assert_eq!(ids.get(1), None);
+    assert_eq!(
+        ids.values[4..],
+        [3.5, 1.8708286933869707, 1., 2.25, 3.5, 4.75, 6.]
+    );
+
+    let city: &Series<String> = summary.get_column("city").unwrap();
+    assert_eq!(city.values[..4], ["5", "2", "Oslo", "3"]);
+    assert_eq!(city.null_count(), 7);
+
+    let names: &Series<String> = summary.get_column("name").unwrap();
+    assert_eq!(names.get(1), Some(&"6".to_string()));
+    assert_eq!(names.get(3), Some(&"1".to_string()));
+}
+
+#[test]
+fn test_cov_corr() {
+    let mut df = DataFrame::new();
+    let mut a = Series::from_vec(vec![1_i32, 2, 3, 4]);
+    a.set_name("a");
+    let mut b = Series::from_options(vec![Some(2.), Some(4.), Some(6.), None]);
+    b.set_name("b");
+    let mut c = Series::from_vec(["w", "x", "y", "z"].iter().map(|s| s.to_string()).collect());
+    c.set_name("c");
+    let mut d = Series::from_options(vec![None, Some(1_u8), None, None]);
+    d.set_name("d");
+    df.add_column(a).unwrap();
+    df.add_column(b).unwrap();
+    df.add_column(c).unwrap();
+    df.add_column(d).unwrap();
+
+    // String columns are skipped, missing values excluded per pair
+    let cov = df.cov();
+    assert_eq!(cov.index().values, vec!["a", "b", "d"]);
+    assert_eq!(cov.n_columns(), 3);
+    let col: &Series<f64> = cov.get_column("a").unwrap();
+    assert_eq!(col.get(0), Some(&(5. / 3.)));
+    assert_eq!(col.get(1), Some(&2.));
+    assert_eq!(col.get(2), None);
+    let col: &Series<f64> = cov.get_column("b").unwrap();
+    assert_eq!(col.get(0), Some(&2.));
+
+    let corr = df.corr(CorrelationMethod::Pearson);
+    let col: &Series<f64> = corr.get_column("b").unwrap();
+    assert_eq!(col.values[..2], [1., 1.]);
+    assert_eq!(col.get(2), None);
+    let col: &Series<f64> = corr.get_column("d").unwrap();
+    assert_eq!(col.null_count(), 3);
+}
+
+#[test]
+fn test_isin_between_where_mask() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    let names = df
+        .isin("name", &["Bob".to_string(), "Eve".to_string()])
+        .unwrap();
+    assert_eq!(names.values, vec![false, true, false, false, true, false]);
+    let ids = df.between("id", 2, 4, Inclusive::Neither).unwrap();
+    assert_eq!(ids.values, vec![false, false, true, false, false, false]);
+    assert!(df.isin("city", &["Oslo".to_string()]).is_err());
+    assert!(df.between("id", 2., 4., Inclusive::Both).is_err());
+
+    let mut filtered = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+    filtered
+        .filter_by_mask(&(names.clone() | ids).unwrap())
+        .unwrap();
+    assert_eq!(filtered.len(), 3);
+
+    let kept = df.where_(&names).unwrap();
+    assert_eq!(kept.len(), 6);
+    let city = kept.get_categorical_column("city").unwrap();
+    assert_eq!(city.get(1), Some("Bergen"));
+    assert_eq!(city.null_count(), 4);
+    let id: &Series<i32> = kept.get_column("id").unwrap();
+    assert_eq!(id.null_count(), 4);
+
+    let masked = df.mask(&names).unwrap();
+    let name: &Series<String> = masked.get_column("name").unwrap();
+    assert_eq!(name.get(0), Some(&"Ann".to_string()));
+    assert_eq!(name.get(1), None);
+    assert!(df.mask(&Series::from_vec(vec![true])).is_err());
+}
+
+#[test]
+fn test_nlargest_nsmallest() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let df = Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    let top = df.nlargest(2, "id").unwrap();
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.index().values, vec![5, 4]);
+    let names: &Series<String> = top.get_column("name").unwrap();
+    assert_eq!(names.values, vec!["Fay", "Eve"]);
+
+    // Categorical columns order by category, skipping nulls
+    let bottom = df.nsmallest(3, "city").unwrap();
+    let city = bottom.get_categorical_column("city").unwrap();
+    assert_eq!(
+        (0..3).map(|i| city.get(i).unwrap()).collect::<Vec<_>>(),
+        vec!["Bergen", "Bergen", "Oslo"]
+    );
+    assert_eq!(bottom.index().values, vec![1, 5, 0]);
+
+    assert!(df.nlargest(2, "missing").is_err());
+}
+
+#[test]
+fn test_drop_duplicates() {
+    let path = format!(
+        "{}/tests/data/categorical_csv.csv",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let read = || Reader::new(&path).infer_categorical(0.5).read().unwrap();
+
+    // Every row is distinct by default
+    let mut df = read();
+    df.drop_duplicates(None, Keep::First).unwrap();
+    assert_eq!(df.len(), 6);
+
+    // Cities Oslo, Bergen, Oslo, null, Oslo, Bergen
+    let mut df = read();
+    df.drop_duplicates(Some(&["city"]), Keep::First).unwrap();
+    assert_eq!(df.index().values, vec![0, 1, 3]);
+    let names: &Series<String> = df.get_column("name").unwrap();
+    assert_eq!(names.values, vec!["Ann", "Bob", "Dan"]);
+
+    let mut df = read();
+    df.drop_duplicates(Some(&["city"]), Keep::Last).unwrap();
+    assert_eq!(df.index().values, vec![3, 4, 5]);
+
+    let mut df = read();
+    let mut group = Series::from_vec(vec![1_i32, 1, 2, 1, 1, 1]);
+    group.set_name("group");
+    df.add_column(group).unwrap();
+    df.drop_duplicates(Some(&["city", "group"]), Keep::None)
+        .unwrap();
+    assert_eq!(df.index().values, vec![2, 3]);
+
+    assert!(df.drop_duplicates(Some(&["missing"]), Keep::First).is_err());
+    assert_eq!(df.len(), 2);
+}
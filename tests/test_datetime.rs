@@ -0,0 +1,181 @@
+use blackjack::prelude::*;
+use tempfile::tempdir;
+
+#[test]
+fn test_parse_iso8601() {
+    let dt: DateTime = "1970-01-01".parse().unwrap();
+    assert_eq!(dt.timestamp_nanos(), 0);
+
+    let dt: DateTime = "2020-02-29 08:30".parse().unwrap();
+    assert_eq!((dt.year(), dt.month(), dt.day()), (2020, 2, 29));
+    assert_eq!((dt.hour(), dt.minute(), dt.second()), (8, 30, 0));
+    assert_eq!(dt.dayofyear(), 60);
+
+    let dt: DateTime = "2019-04-28T13:45:10.123Z".parse().unwrap();
+    assert_eq!(dt.nanosecond(), 123_000_000);
+    assert_eq!(dt.offset(), Some(0));
+    assert_eq!(dt.to_string(), "2019-04-28T13:45:10.123+00:00");
+
+    // Offsets change the components, but not the instant
+    let dt: DateTime = "2019-04-28T23:00:00-02:00".parse().unwrap();
+    let utc: DateTime = "2019-04-29T01:00:00".parse().unwrap();
+    assert_eq!(dt.timestamp_nanos(), utc.timestamp_nanos());
+    assert_eq!((dt.day(), dt.hour()), (28, 23));
+
+    // Before the epoch
+    let dt: DateTime = "1969-12-31T23:59:59".parse().unwrap();
+    assert_eq!(dt.timestamp_nanos(), -1_000_000_000);
+    assert_eq!(dt.weekday(), 2);
+    assert_eq!(dt.to_string(), "1969-12-31T23:59:59");
+
+    for invalid in &[
+        "2019-13-01",
+        "2019-02-29",
+        "2019-04-28T25:00",
+        "2019-04-28X",
+        "2020-01-01T00:00:00+02:",
+        "2020-01-01T00:00:00+02:3",
+        "foo",
+    ] {
+        assert!(invalid.parse::<DateTime>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_parse_and_format_with_format() {
+    let dt =
+        DateTime::parse_from_str("28 apr 19 13:45:10.5 +0130", "%d %b %y %H:%M:%S.%f %z").unwrap();
+    assert_eq!(dt.year(), 2019);
+    assert_eq!(dt.month(), 4);
+    assert_eq!(dt.offset(), Some(5400));
+    assert_eq!(dt.nanosecond(), 500_000_000);
+    assert_eq!(
+        dt.format("%Y-%m-%d %H:%M:%S.%f %z %b %%"),
+        "2019-04-28 13:45:10.500000000 +0130 Apr %"
+    );
+
+    assert!(DateTime::parse_from_str("2019/04/28", "%Y-%m-%d").is_err());
+    assert!(DateTime::parse_from_str("2019-04-28", "%Q").is_err());
+}
+
+#[test]
+fn test_datetime_series() {
+    let mut series = Series::from_vec(vec![
+        "2019-04-28T13:45:10".to_string(),
+        "2020-01-01T00:00:00".to_string(),
+        "".to_string(),
+    ]);
+    series.set_name("dates");
+    series.set_null(2);
+
+    let dates = series.to_datetime(None).unwrap();
+    assert_eq!(dates.dtype(), Some(DType::DATETIME));
+    assert_eq!(dates.name(), Some("dates".to_string()));
+    assert_eq!(dates.null_count(), 1);
+
+    let months = dates.month();
+    assert_eq!(months.get(0), Some(&4));
+    assert_eq!(months.get(2), None);
+    assert_eq!(dates.hour().values[..2], [13, 0]);
+
+    // Datetimes compare and order by instant
+    let cutoff: DateTime = "2019-12-31".parse().unwrap();
+    let mask = dates.gt(cutoff).unwrap();
+    assert_eq!(mask.values[..2], [false, true]);
+
+    let series = Series::from_vec(vec!["2019-04-28".to_string(), "oops".to_string()]);
+    assert!(series.to_datetime(None).is_err());
+}
+
+#[test]
+fn test_read_write_datetime_csv() {
+    let path = format!("{}/tests/data/datetime_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    let df = Reader::new(&path)
+        .datetime_format("%d/%m/%Y")
+        .read()
+        .unwrap();
+
+    let created: &Series<DateTime> = df.get_column("created").unwrap();
+    assert_eq!(created.null_count(), 1);
+    assert_eq!(created.year().values[..2], [2019, 2020]);
+    assert_eq!(created[3].offset(), Some(7200));
+
+    let day: &Series<DateTime> = df.get_column("day").unwrap();
+    assert_eq!(day.month().get(1), Some(&3));
+
+    let tdir = tempdir().unwrap();
+    let out_path = tdir.path().join("out.csv");
+    let out_path_str = out_path.to_str().unwrap();
+    Writer::new(&out_path_str)
+        .datetime_format("%Y%m%d")
+        .write(df)
+        .unwrap();
+
+    let contents = std::fs::read_to_string(&out_path).unwrap();
+    assert!(contents.contains("20190428"));
+    assert!(contents.contains("20200301"));
+}
+
+#[test]
+fn test_duration_parse_and_format() {
+    let duration = Duration::from_days(1) + Duration::from_minutes(150) + Duration::from_nanos(500);
+    assert_eq!(duration.to_string(), "1 days 02:30:00.0000005");
+    assert_eq!(duration.to_string().parse::<Duration>().unwrap(), duration);
+
+    assert_eq!(
+        (-Duration::from_seconds(90)).to_string(),
+        "-0 days 00:01:30"
+    );
+    assert_eq!(
+        "-0 days 00:01:30".parse::<Duration>().unwrap(),
+        -Duration::from_seconds(90)
+    );
+    assert_eq!(
+        "36:00:00".parse::<Duration>().unwrap(),
+        Duration::from_hours(36)
+    );
+
+    for invalid in &[
+        "1 weeks 00:00:00",
+        "00:61:00",
+        "1 days",
+        "foo",
+        "999999999999 days 00:00:00",
+        "99999999999999:00:00",
+    ] {
+        assert!(invalid.parse::<Duration>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_datetime_duration_arithmetic() {
+    let start: DateTime = "2019-04-28T23:00:00+02:00".parse().unwrap();
+    let end = start + Duration::from_hours(2);
+    assert_eq!(end.to_string(), "2019-04-29T01:00:00+02:00");
+    assert_eq!(end - start, Duration::from_hours(2));
+    assert_eq!(end - Duration::from_hours(2), start);
+
+    let starts = Series::from_vec(vec![start, start, start]);
+    let mut ends =
+        Series::from_options(vec![Some(end), None, Some(start + Duration::from_days(3))]);
+    ends.set_name("end");
+
+    let elapsed = (ends.clone() - starts.clone()).unwrap();
+    assert_eq!(elapsed.dtype(), Some(DType::DURATION));
+    assert_eq!(elapsed.name(), Some("end".to_string()));
+    assert_eq!(elapsed.get(1), None);
+    assert_eq!(elapsed.sum(), Duration::from_hours(74));
+    assert_eq!(elapsed.min().unwrap(), Duration::from_hours(2));
+    assert_eq!(elapsed.mean_duration().unwrap(), Duration::from_hours(37));
+    assert_eq!(elapsed.total_seconds().get(0), Some(&7200.0));
+
+    let shifted = (starts.clone() + elapsed).unwrap();
+    assert_eq!(shifted.get(0), ends.get(0));
+    assert_eq!(shifted.null_count(), 1);
+
+    let earlier = starts.clone() - Duration::from_days(1);
+    assert_eq!(earlier[0].day(), 27);
+    assert_eq!(earlier.min().unwrap(), start - Duration::from_days(1));
+
+    assert!((ends - Series::from_vec(vec![start])).is_err());
+}
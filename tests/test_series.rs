@@ -8,8 +8,8 @@ use float_cmp::ApproxEq;
 /* Series <op> Series tests */
 #[test]
 fn test_series_op_series_impls() {
-    let series1 = Series::from_vec(vec![1, 1, 1, 1, 1]);
-    let series2 = Series::from_vec(vec![1, 1, 1, 1, 1, 1]);
+    let series1 = Series::from_vec(vec![1_i32, 1, 1, 1, 1]);
+    let series2 = Series::from_vec(vec![1_i32, 1, 1, 1, 1, 1]);
 
     // Adding a series with another series of a different shape should Err
     let result = (series1.clone() + series2).is_ok();
@@ -30,6 +30,46 @@ fn test_series_op_series_inplace() {
     assert_eq!(series1.sum(), 8);
 }
 
+#[test]
+fn test_series_comparisons() {
+    let series = Series::from_options(vec![Some(1), Some(2), None, Some(4)]);
+    let other = Series::from_vec(vec![4, 2, 2, 1]);
+
+    // Series <op> scalar
+    assert_eq!(series.gt(1).unwrap().values[..2], [false, true]);
+    assert_eq!(series.ge(2).unwrap().values[..2], [false, true]);
+    assert_eq!(series.lt(2).unwrap().values[..2], [true, false]);
+    assert_eq!(series.le(1).unwrap().values[..2], [true, false]);
+    assert_eq!(series.ne(1).unwrap().values[..2], [false, true]);
+
+    // Series <op> Series
+    let mask = series.eq(&other).unwrap();
+    assert_eq!(mask.get(1), Some(&true));
+    assert_eq!(mask.get(2), None);
+    assert!(series.eq(&Series::from_vec(vec![1])).is_err());
+
+    // Combining masks
+    let mask = (series.gt(1).unwrap() & series.lt(4).unwrap()).unwrap();
+    assert_eq!(series.filter_by_mask(&mask).unwrap().values, vec![2]);
+
+    let mask = (series.lt(2).unwrap() | series.gt(3).unwrap()).unwrap();
+    assert_eq!(series.filter_by_mask(&mask).unwrap().values, vec![1, 4]);
+
+    let mask = !series.eq(2).unwrap();
+    assert_eq!(mask.null_count(), 1);
+    assert_eq!(series.filter_by_mask(&mask).unwrap().values, vec![1, 4]);
+
+    // Ordering of whole series ignores the placeholder values of nulls, as equality does
+    let mut placeholder = series.clone();
+    placeholder.values[2] = 3;
+    assert_eq!(placeholder, series);
+    assert_eq!(
+        placeholder.partial_cmp(&series),
+        Some(std::cmp::Ordering::Equal)
+    );
+    assert!(series < other);
+}
+
 #[test]
 fn test_into_iter() {
     let series: Series<i32> = Series::from_vec(vec![1, 2, 3, 4]);
@@ -53,6 +93,82 @@ fn test_isna() {
     );
 }
 
+#[test]
+fn test_missing_values() {
+    let mut series = Series::from_options(vec![Some(1), None, Some(3), None]);
+
+    assert_eq!(
+        series.isna().collect::<Vec<bool>>(),
+        vec![false, true, false, true]
+    );
+    assert_eq!(
+        series.notna().collect::<Vec<bool>>(),
+        vec![true, false, true, false]
+    );
+    assert_eq!(series.count(), 2);
+    assert_eq!(series.null_count(), 2);
+
+    // Aggregations skip nulls
+    assert_eq!(series.sum(), 4);
+    assert_eq!(series.mean().unwrap(), 2.0);
+    assert_eq!(series.min().unwrap(), 1);
+    assert_eq!(series.max().unwrap(), 3);
+    assert_eq!(series.var(1_f64).unwrap(), 2.0);
+
+    // Nulls survive operations on the series
+    series.append(5);
+    series.drop_positions(vec![0]);
+    assert_eq!(series.get(0), None);
+    assert_eq!(series.get(1), Some(&3));
+    let series = series.astype::<f64>().unwrap();
+    assert_eq!(series.count(), 2);
+    let series = series * 2_f64;
+    assert_eq!(series.sum(), 16.);
+
+    // Null slots are skipped by operations between series, rather than dividing by zero
+    let numerator = Series::from_options(vec![Some(6_i32), None, Some(8)]);
+    let divisor = Series::from_options(vec![Some(2_i32), Some(4), None]);
+    let quotient = (numerator / divisor).unwrap();
+    assert_eq!(quotient.get(0), Some(&3));
+    assert_eq!((quotient.get(1), quotient.get(2)), (None, None));
+
+    // Float NaNs are treated as missing as well
+    let series = Series::from_options(vec![Some(1.), Some(num::Float::nan()), None]);
+    assert_eq!(series.count(), 1);
+    assert_eq!(series.null_count(), 1);
+    assert_eq!(series.sum(), 1.);
+
+    // String series can hold nulls
+    let mut series = Series::from_vec(vec!["a".to_string(), "b".to_string()]);
+    series.set(0, None);
+    assert_eq!(series.isna().collect::<Vec<bool>>(), vec![true, false]);
+    series.set(0, Some("c".to_string()));
+    assert_eq!(
+        series,
+        Series::from_vec(vec!["c".to_string(), "b".to_string()])
+    );
+}
+
+#[test]
+fn test_filter_by_mask() {
+    let mut series = Series::from_options(vec![Some(1), None, Some(3), Some(4)]);
+    series.set_name("series");
+
+    let mut mask = Series::from_vec(vec![true, true, false, true]);
+    mask.set_null(3);
+    assert_eq!(mask.dtype(), Some(DType::BOOL));
+
+    let selected = series.filter_by_mask(&mask).unwrap();
+    assert_eq!(selected.name(), Some("series".to_string()));
+    assert_eq!(selected.len(), 2);
+    assert_eq!(selected.get(0), Some(&1));
+    assert_eq!(selected.get(1), None);
+
+    // Mask must be of the same length
+    let mask = Series::from_vec(vec![true]);
+    assert!(series.filter_by_mask(&mask).is_err());
+}
+
 #[test]
 fn test_all() {
     let series = Series::from_vec(vec![1, 2, 3, 4, 5]);
@@ -182,32 +298,49 @@ fn test_rolling() {
     assert_eq!(rolled[3], 7.0);
     assert_eq!(rolled[4], 8.0);
     assert_eq!(rolled[5], 12.0);
+
+    // Windows containing a null are NaN, rather than counting its placeholder
+    let series = Series::from_options(vec![Some(1), None, Some(3), Some(5)]);
+    let roller = series.rolling(2);
+    for rolled in [
+        roller.mean().unwrap(),
+        roller.sum().unwrap(),
+        roller.var(0.).unwrap(),
+        roller.std(0.).unwrap(),
+        roller.median().unwrap(),
+        roller.min().unwrap(),
+        roller.max().unwrap(),
+    ] {
+        assert!(rolled.values[..3].iter().all(|v| v.is_nan()));
+        assert!(!rolled[3].is_nan());
+    }
+    assert_eq!(roller.mean().unwrap()[3], 4.0);
 }
 
 #[test]
 fn test_unique() {
     let series = Series::from_vec(vec![1, 2, 1, 0, 1, 0, 1, 1]);
     let unique = series.unique();
-    assert_eq!(unique, Series::from_vec(vec![0, 1, 2]));
+    assert_eq!(unique, Series::from_vec(vec![1, 2, 0]));
 }
 
 #[test]
 fn test_series_scalar_ops() {
-    let base_series = Series::arange(0, 5);
+    let base_series: Series<i32> = Series::arange(0, 5);
 
     // Test Mul
     let series = base_series.clone();
-    let series = series * 2;
+    let series = series * 2_i32;
     assert_eq!(series.sum(), 20);
 
     // Test Add
     let series = base_series.clone();
-    let series = series + 2;
+    let series = series + 2_i32;
     assert_eq!(series.sum(), 20);
 
     // Test Sub
     let series = base_series.clone();
-    let series = series - 2;
+    let series = series - 2_i32;
     assert_eq!(series.sum(), 0);
 
     // Test Div, convert to f32 so floats don't get rounded during
@@ -320,3 +453,877 @@ fn test_into_from_raw() {
     let recovered_series = Series::from_raw(ptr);
     assert_eq!(recovered_series, series_clone)
 }
+
+#[test]
+fn test_categorical() {
+    let mut series = Series::from_options(vec![
+        Some("b".to_string()),
+        Some("a".to_string()),
+        None,
+        Some("b".to_string()),
+    ]);
+    series.set_name("letters");
+
+    let categorical = series.to_categorical();
+    assert_eq!(categorical.len(), 4);
+    assert_eq!(categorical.name(), Some("letters".to_string()));
+    assert_eq!(
+        categorical.categories(),
+        &["b".to_string(), "a".to_string()]
+    );
+    assert_eq!(categorical.codes().get(3), Some(&0));
+    assert_eq!(categorical.get(2), None);
+    assert_eq!(categorical.to_series(), series);
+
+    let (categories, counts) = categorical.value_counts(true, true);
+    assert_eq!(categories.values, vec!["b".to_string(), "a".to_string()]);
+    assert_eq!(counts.values, vec![2, 1]);
+    assert_eq!(counts.name(), Some("letters".to_string()));
+
+    let (categories, counts) = categorical.value_counts_normalized(false, false);
+    assert_eq!(categories.get(2), None);
+    assert_eq!(counts.values, vec![0.5, 0.25, 0.25]);
+
+    let values = Series::from_vec(vec![1, 2, 3, 4]);
+    let grouped = categorical.groupby(&values);
+    assert_eq!(grouped.sum().values, vec![5, 2]);
+
+    let mut categorical = categorical;
+    categorical.drop_positions(vec![0, 3]);
+    assert_eq!(categorical.len(), 2);
+    let (_, counts) = categorical.value_counts(true, true);
+    assert_eq!(counts.values, vec![1, 0]);
+
+    let categories = vec!["x".to_string(), "x".to_string()];
+    assert!(Categorical::from_codes(Series::from_vec(vec![0]), categories).is_err());
+}
+
+#[test]
+fn test_sort_values() {
+    let mut series = Series::from_vec(vec![2.0, f64::NAN, 1.0, 2.0, 3.0]);
+    series.set_name("floats");
+    series.set_null(4);
+
+    let sorted = series.sort_values(true);
+    assert_eq!(sorted.name(), Some("floats".to_string()));
+    assert_eq!(sorted.dtype(), Some(DType::F64));
+    assert_eq!(sorted.values[..3], [1.0, 2.0, 2.0]);
+    assert!(sorted[3].is_nan());
+    assert_eq!(sorted.get(4), None);
+
+    let descending = SortOptions::new()
+        .ascending(false)
+        .stable(true)
+        .na_position(NaPosition::First);
+    assert_eq!(series.argsort_with(&descending), vec![1, 4, 0, 3, 2]);
+    assert_eq!(series.sort_values_with(&descending).null_count(), 1);
+
+    // Stable sorts keep the original order of equal elements
+    let series = Series::from_vec(vec![1, 0, 1, 0, 1]);
+    let stable = SortOptions::new().stable(true);
+    assert_eq!(series.argsort_with(&stable), vec![1, 3, 0, 2, 4]);
+    assert_eq!(series.sort_values(false).values, vec![1, 1, 1, 0, 0]);
+
+    let series = Series::from_vec(vec!["b".to_string(), "a".to_string()]);
+    assert_eq!(series.sort_values(true).values, vec!["a", "b"]);
+
+    // NaN no longer panics `unique`
+    let series = Series::from_vec(vec![1.0, f64::NAN, 1.0]);
+    assert_eq!(series.unique().len(), 2);
+}
+
+#[test]
+fn test_rank() {
+    let mut series = Series::from_vec(vec![3.0, f64::NAN, 1.0, 3.0, 2.0, 3.0]);
+    series.set_name("scores");
+
+    let ranks = series.rank(RankMethod::Average, false);
+    assert_eq!(ranks.name(), Some("scores".to_string()));
+    assert_eq!(ranks.len(), 6);
+    assert_eq!(ranks.get(1), None);
+    assert_eq!(ranks.get(0), Some(&4.0));
+    assert_eq!(ranks.get(2), Some(&1.0));
+
+    let first = series.rank(RankMethod::First, false);
+    assert_eq!(
+        (first.get(0), first.get(3), first.get(5)),
+        (Some(&3.0), Some(&4.0), Some(&5.0))
+    );
+
+    let dense = series.rank(RankMethod::Dense, false);
+    assert_eq!(dense.get(5), Some(&3.0));
+
+    // Percentile ranks divide by the number of non-missing values
+    let pct = series.rank(RankMethod::Max, true);
+    assert_eq!(pct.get(0), Some(&1.0));
+    assert_eq!(pct.get(4), Some(&0.4));
+
+    let series = Series::from_options(vec![Some("b".to_string()), None, Some("a".to_string())]);
+    let ranks = series.rank(RankMethod::Min, false);
+    assert_eq!((ranks.get(0), ranks.get(1)), (Some(&2.0), None));
+}
+
+#[test]
+fn test_value_counts() {
+    let mut series = Series::from_vec(vec![1.0, f64::NAN, 2.0, 1.0, f64::NAN, 3.0, 2.0, 1.0]);
+    series.set_name("floats");
+    series.set_null(5);
+
+    let (values, counts) = series.value_counts(true, true);
+    assert_eq!(values.name(), Some("floats".to_string()));
+    assert_eq!(values.values, vec![1.0, 2.0]);
+    assert_eq!(counts.values, vec![3, 2]);
+
+    // NaN and null are counted together as missing
+    let (values, counts) = series.value_counts(true, false);
+    assert_eq!(values.len(), 3);
+    assert_eq!(values.get(1), None);
+    assert_eq!(counts.values, vec![3, 3, 2]);
+
+    let (values, counts) = series.value_counts_normalized(false, true);
+    assert_eq!(values.values, vec![1.0, 2.0]);
+    assert_eq!(counts.values, vec![0.6, 0.4]);
+
+    let series = Series::from_vec(vec![true, false, true]);
+    let (values, counts) = series.value_counts(true, true);
+    assert_eq!(values.values, vec![true, false]);
+    assert_eq!(counts.values, vec![2, 1]);
+}
+
+#[test]
+fn test_cumulative() {
+    let mut series = Series::from_vec(vec![2, 1, 0, 3, 4]);
+    series.set_name("ints");
+    series.set_null(2);
+
+    let cumsum = series.cumsum();
+    assert_eq!(cumsum.name(), Some("ints".to_string()));
+    assert_eq!(cumsum.get(2), None);
+    assert_eq!(cumsum.values[3..], [6, 10]);
+    assert_eq!(series.cumprod().values[3..], [6, 24]);
+    assert_eq!(series.cummin().values[..2], [2, 1]);
+    assert_eq!(series.cummin().get(4), Some(&1));
+    assert_eq!(series.cummax().get(4), Some(&4));
+    assert_eq!(series.cumcount().get(4), Some(&3));
+
+    let series = Series::from_vec(vec!["b".to_string(), "a".to_string(), "c".to_string()]);
+    assert_eq!(series.cummax().values, vec!["b", "b", "c"]);
+
+    // Per group, aligned with the grouped series
+    let series = Series::from_vec(vec![1.0, 2.0, f64::NAN, 4.0, 5.0]);
+    let keys = Series::from_options(vec![Some(0.), Some(1.), Some(0.), None, Some(0.)]);
+    let grouped = series.groupby(&keys);
+
+    let cumsum = grouped.cumsum();
+    assert_eq!(cumsum.len(), 5);
+    assert_eq!((cumsum.get(0), cumsum.get(1)), (Some(&1.0), Some(&2.0)));
+    assert_eq!((cumsum.get(2), cumsum.get(3)), (None, None));
+    assert_eq!(cumsum.get(4), Some(&6.0));
+    assert_eq!(grouped.cummax().get(4), Some(&5.0));
+    assert_eq!(grouped.cumcount().get(4), Some(&1));
+}
+
+#[test]
+fn test_shift_diff_pct_change() {
+    let mut series = Series::from_options(vec![Some(2), Some(4), None, Some(5), Some(10)]);
+    series.set_name("ints");
+
+    let shifted = series.shift(2);
+    assert_eq!(shifted.name(), Some("ints".to_string()));
+    assert_eq!(shifted.dtype(), Some(DType::I32));
+    assert_eq!((shifted.get(0), shifted.get(1)), (None, None));
+    assert_eq!(shifted.get(2), Some(&2));
+    assert_eq!(shifted.get(4), None);
+    assert_eq!(series.shift(-4).get(0), Some(&10));
+    assert_eq!(series.shift(5).null_count(), 5);
+    assert_eq!(series.shift(i64::MIN).null_count(), 5);
+    assert_eq!(series.diff(i64::MAX).null_count(), 5);
+
+    let diff = series.diff(1);
+    assert_eq!(diff.name(), Some("ints".to_string()));
+    assert_eq!(diff.get(0), None);
+    assert_eq!(diff.get(1), Some(&2.0));
+    assert_eq!((diff.get(2), diff.get(3)), (None, None));
+    assert_eq!(diff.get(4), Some(&5.0));
+    assert_eq!(series.diff(-1).get(0), Some(&-2.0));
+
+    let pct = series.pct_change(1);
+    assert_eq!(pct.get(1), Some(&1.0));
+    assert_eq!(pct.get(4), Some(&1.0));
+    assert_eq!(series.pct_change(3).get(3), Some(&1.5));
+}
+
+#[test]
+fn test_fill_missing() {
+    let mut series = Series::from_vec(vec![
+        f64::NAN,
+        1.0,
+        f64::NAN,
+        f64::NAN,
+        f64::NAN,
+        5.0,
+        7.0,
+        f64::NAN,
+    ]);
+    series.set_name("floats");
+    series.set_null(3);
+
+    let filled = series.fillna(0.0);
+    assert_eq!(filled.name(), Some("floats".to_string()));
+    assert_eq!(filled.null_count(), 0);
+    assert_eq!(filled.values, vec![0., 1., 0., 0., 0., 5., 7., 0.]);
+
+    // NaN and nulls both count towards the limit
+    let ffilled = series.ffill(Some(2));
+    assert!(ffilled[0].is_nan());
+    assert_eq!(ffilled.values[1..4], [1., 1., 1.]);
+    assert!(ffilled[4].is_nan());
+    assert_eq!(ffilled.get(7), Some(&7.0));
+
+    let bfilled = series.bfill(None);
+    assert_eq!(bfilled.values[..6], [1., 1., 5., 5., 5., 5.]);
+    assert!(bfilled[7].is_nan());
+
+    let linear = series.interpolate(InterpolationMethod::Linear).unwrap();
+    assert_eq!(linear.get(0), None);
+    assert_eq!(linear.values[1..7], [1., 2., 3., 4., 5., 7.]);
+    assert_eq!(linear.get(7), None);
+
+    let nearest = series.interpolate(InterpolationMethod::Nearest).unwrap();
+    assert_eq!(nearest.values[1..7], [1., 1., 1., 5., 5., 7.]);
+
+    // A spline through points on a line stays on the line
+    let series = Series::from_options(vec![Some(0), None, Some(2), Some(3), None, Some(5)]);
+    let spline = series
+        .interpolate(InterpolationMethod::CubicSpline)
+        .unwrap();
+    assert!((spline[1] - 1.).abs() < 1e-9);
+    assert!((spline[4] - 4.).abs() < 1e-9);
+
+    let strings = Series::from_options(vec![None, Some("a".to_string()), None]);
+    assert_eq!(strings.ffill(None).get(2), Some(&"a".to_string()));
+    assert_eq!(
+        strings.fillna("z".to_string()).get(0),
+        Some(&"z".to_string())
+    );
+}
+
+#[test]
+fn test_elementwise_math() {
+    let mut series = Series::from_vec(vec![-2.5, 0.0, 4.0, f64::NAN]);
+    series.set_name("floats");
+    series.set_null(1);
+
+    let abs = series.clone().abs();
+    assert_eq!(abs.name(), Some("floats".to_string()));
+    assert_eq!(abs.get(1), None);
+    assert_eq!(abs.values[..1], [2.5]);
+    assert_eq!(abs.get(2), Some(&4.0));
+    assert!(abs[3].is_nan());
+
+    assert_eq!(series.clone().sign().values[..3], [-1., 0., 1.]);
+    assert_eq!(
+        series.clone().clip(Some(-1.), Some(3.)).values[..3],
+        [-1., 0., 3.]
+    );
+    assert!(series.clone().clip(Some(-1.), Some(3.))[3].is_nan());
+    assert_eq!(series.clone().sqrt().get(2), Some(&2.0));
+    assert!(series.clone().sqrt()[0].is_nan());
+    assert_eq!(series.clone().pow(2).get(0), Some(&6.25));
+    assert_eq!(series.clone().powf(0.5).get(2), Some(&2.0));
+    assert_eq!(series.clone().round(0).get(0), Some(&-3.0));
+    assert_eq!(series.clone().exp().ln().get(2), Some(&4.0));
+
+    let series = Series::from_vec(vec![1_f32, 10., 1000.]).log10();
+    assert_eq!(series.values, vec![0., 1., 3.]);
+    assert_eq!(series.dtype(), Some(DType::F32));
+
+    let series = Series::from_options(vec![Some(-3), None, Some(7)]);
+    let abs = series.clone().abs();
+    assert_eq!((abs.get(0), abs.get(1)), (Some(&3), None));
+    assert_eq!(series.clone().sign().values, vec![-1, 0, 1]);
+    assert_eq!(series.clip(Some(0), None).get(0), Some(&0));
+}
+
+#[test]
+fn test_type_promotion() {
+    let mut ints = Series::from_options(vec![Some(1_i32), None, Some(3)]);
+    ints.set_name("ints");
+    let floats = Series::from_vec(vec![0.5_f64, 1.5, 2.5]);
+
+    let sum = (ints.clone() + floats.clone()).unwrap();
+    assert_eq!(sum.dtype(), Some(DType::F64));
+    assert_eq!(sum.name(), Some("ints".to_string()));
+    assert_eq!((sum.get(0), sum.get(1)), (Some(&1.5), None));
+    assert_eq!(sum.get(2), Some(&5.5));
+    assert_eq!((floats.clone() - ints.clone()).unwrap().get(2), Some(&-0.5));
+
+    let wide = Series::from_vec(vec![10_i64, 20, 30]);
+    let product: Series<i64> = (ints.clone() * wide.clone()).unwrap();
+    assert_eq!(product.get(2), Some(&90));
+
+    // Null divisors are skipped rather than dividing by zero
+    let quotient: Series<i64> = (wide / ints.clone()).unwrap();
+    assert_eq!((quotient.get(0), quotient.get(1)), (Some(&10), None));
+
+    let mixed = (Series::from_vec(vec![200_u8]) + Series::from_vec(vec![100_i8])).unwrap();
+    assert_eq!(mixed.values, vec![300_i16]);
+    let mixed = (Series::from_vec(vec![1_u64]) + Series::from_vec(vec![-1_i64])).unwrap();
+    assert_eq!(mixed.values, vec![0_f64]);
+    assert!((ints.clone() + Series::from_vec(vec![1.0_f32])).is_err());
+
+    let scaled: Series<f64> = ints.clone() * 0.5;
+    assert_eq!(scaled.dtype(), Some(DType::F64));
+    assert_eq!(scaled.name(), Some("ints".to_string()));
+    assert_eq!((scaled.get(0), scaled.get(1)), (Some(&0.5), None));
+    let scaled: Series<f32> = Series::from_vec(vec![3_u8]) / 2.0_f32;
+    assert_eq!(scaled.values, vec![1.5]);
+
+    // Scalars of a wider dtype of the same kind promote as series do
+    let widened: Series<i64> = ints.clone() + 5_i64;
+    assert_eq!(widened.dtype(), Some(DType::I64));
+    assert_eq!((widened.get(0), widened.get(1)), (Some(&6), None));
+    let widened: Series<f64> = Series::from_vec(vec![0.5_f32]) + 1_f64;
+    assert_eq!(widened.values, vec![1.5]);
+    let widened: Series<i16> = Series::from_vec(vec![200_u8]) * -2_i8;
+    assert_eq!(widened.values, vec![-400]);
+    let widened: Series<f64> = Series::from_vec(vec![1.5_f64]) * 2_i32;
+    assert_eq!(widened.values, vec![3.]);
+
+    // Same dtype scalar operations are unchanged
+    let same: Series<u8> = Series::from_vec(vec![3_u8]) + 1_u8;
+    assert_eq!(same.values, vec![4]);
+
+    // Dynamic dispatch on containers
+    let left = GenericSeriesContainer::I32(ints);
+    let right = GenericSeriesContainer::F32(Series::from_vec(vec![1.0_f32, 2.0, 3.0]));
+    match (left + right).unwrap() {
+        GenericSeriesContainer::F64(series) => {
+            assert_eq!((series.get(0), series.get(1)), (Some(&2.0), None));
+            assert_eq!(series.get(2), Some(&6.0));
+        }
+        _ => panic!("Expected f64 series"),
+    }
+    let strings = GenericSeriesContainer::STRING(Series::from_vec(vec!["a".to_string()]));
+    let ints = GenericSeriesContainer::I32(Series::from_vec(vec![1]));
+    assert!((strings - ints).is_err());
+}
+
+#[test]
+fn test_try_assign_ops() {
+    let mut series = Series::from_vec(vec![6, 8, 10]);
+    series.set_name("ints");
+
+    let mut divisor = Series::from_vec(vec![2, 0, 5]);
+    divisor.set_null(1);
+    series.try_div_assign(divisor).unwrap();
+    assert_eq!(series.name(), Some("ints".to_string()));
+    assert_eq!((series.get(0), series.get(1)), (Some(&3), None));
+    assert_eq!(series.get(2), Some(&2));
+
+    series
+        .try_sub_assign(Series::from_vec(vec![1, 1, 1]))
+        .unwrap();
+    series
+        .try_mul_assign(Series::from_vec(vec![3, 3, 3]))
+        .unwrap();
+    assert_eq!(series.get(0), Some(&6));
+    assert_eq!(series.null_count(), 1);
+
+    let before = series.clone();
+    assert!(series.try_add_assign(Series::from_vec(vec![1, 1])).is_err());
+    assert!(series
+        .try_add_assign(Series::from_vec(vec![1, 1, 1, 1]))
+        .is_err());
+    assert_eq!(series, before);
+}
+
+#[test]
+#[should_panic]
+fn test_assign_op_length_mismatch_panics() {
+    let mut series = Series::from_vec(vec![1, 2, 3]);
+    series += Series::from_vec(vec![1, 2]);
+}
+
+#[test]
+fn test_aligned_ops() {
+    let mut left = Series::from_vec(vec![1.0, 2.0, f64::NAN]);
+    left.set_name("left");
+    let left_labels = Series::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    let right = Series::from_vec(vec![10.0, 20.0, 30.0]);
+    let right_labels = Series::from_vec(vec!["d".to_string(), "b".to_string(), "a".to_string()]);
+
+    let (labels, diff) = left
+        .sub_aligned(&left_labels, &right, &right_labels, None)
+        .unwrap();
+    assert_eq!(labels.values, vec!["a", "b", "c", "d"]);
+    assert_eq!(diff.name(), Some("left".to_string()));
+    assert_eq!((diff.get(0), diff.get(1)), (Some(&-29.0), Some(&-18.0)));
+    assert_eq!((diff.get(2), diff.get(3)), (None, None));
+
+    let (_, product) = left
+        .mul_aligned(&left_labels, &right, &right_labels, Some(1.0))
+        .unwrap();
+    assert_eq!(product.values[..2], [30.0, 40.0]);
+    assert_eq!((product.get(2), product.get(3)), (None, Some(&10.0)));
+
+    let (_, ratio) = right
+        .div_aligned(&right_labels, &left, &left_labels, Some(1.0))
+        .unwrap();
+    assert_eq!(ratio.values[..3], [10.0, 10.0, 30.0]);
+    assert_eq!(ratio.get(3), None);
+
+    let duplicates = Series::from_vec(vec!["a".to_string(), "a".to_string(), "c".to_string()]);
+    assert!(left
+        .add_aligned(&duplicates, &right, &right_labels, None)
+        .is_err());
+    assert!(left
+        .add_aligned(
+            &left_labels,
+            &right,
+            &Series::from_vec(vec!["a".to_string()]),
+            None
+        )
+        .is_err());
+}
+
+#[test]
+fn test_overflow_modes() {
+    let mut series = Series::from_vec(vec![250_u8, 10, 0]);
+    series.set_name("bytes");
+    series.set_null(2);
+    let other = Series::from_vec(vec![3_u8, 20, 1]);
+
+    let sum = series.checked_add(&other).unwrap();
+    assert_eq!(sum.name(), Some("bytes".to_string()));
+    assert_eq!(sum.values[..2], [253, 30]);
+    assert_eq!(sum.get(2), None);
+
+    // The error gives the offending position
+    match series.checked_add_scalar(10) {
+        Err(BlackJackError::ValueError(msg)) => assert!(msg.contains("position 0"), "{}", msg),
+        _ => panic!("Expected an overflow error"),
+    }
+    match series.checked_sub(&other) {
+        Err(BlackJackError::ValueError(msg)) => assert!(msg.contains("position 1"), "{}", msg),
+        _ => panic!("Expected an overflow error"),
+    }
+    assert!(series
+        .checked_div(&Series::from_vec(vec![1, 0, 1]))
+        .is_err());
+    assert!(series.checked_div_scalar(0).is_err());
+    assert_eq!(series.checked_mul_scalar(0).unwrap().values, vec![0, 0, 0]);
+    assert!(series.checked_add(&Series::from_vec(vec![1])).is_err());
+
+    assert_eq!(series.saturating_add_scalar(10).values[..2], [255, 20]);
+    assert_eq!(series.saturating_sub(&other).unwrap().values[..2], [247, 0]);
+    assert_eq!(
+        series.saturating_mul(&other).unwrap().values[..2],
+        [255, 200]
+    );
+    assert_eq!(series.wrapping_add_scalar(10).values[..2], [4, 20]);
+    assert_eq!(series.wrapping_sub_scalar(11).values[..2], [239, 255]);
+    assert_eq!(series.wrapping_mul(&other).unwrap().values[..2], [238, 200]);
+
+    let signed = Series::from_vec(vec![i64::MIN, 4]);
+    assert!(signed.checked_div_scalar(-1).is_err());
+    assert_eq!(signed.wrapping_sub_scalar(1).values, vec![i64::MAX, 3]);
+    assert_eq!(signed.saturating_sub_scalar(1).values, vec![i64::MIN, 3]);
+    assert_eq!(
+        signed
+            .saturating_div(&Series::from_vec(vec![-1, 2]))
+            .unwrap()
+            .values,
+        vec![i64::MAX, 2]
+    );
+    assert_eq!(
+        signed.wrapping_div_scalar(-1).unwrap().get(0),
+        Some(&i64::MIN)
+    );
+    assert!(signed.wrapping_div(&Series::from_vec(vec![1, 0])).is_err());
+    assert_eq!(
+        signed.saturating_mul_scalar(i64::MAX).get(1),
+        Some(&i64::MAX)
+    );
+}
+
+#[test]
+fn test_describe() {
+    let mut series = Series::from_options(vec![Some(4_u8), None, Some(1), Some(7), Some(4)]);
+    series.set_name("scores");
+    let (stats, values) = series.describe();
+    assert_eq!(
+        stats.values,
+        vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+    );
+    assert_eq!(values.name(), Some("scores".to_string()));
+    assert_eq!(values.values[..2], [4., 4.]);
+    assert!(values[2].approx_eq(6_f64.sqrt(), (0.0, 2)));
+    assert_eq!(values.values[3..], [1., 3.25, 4., 4.75, 7.]);
+
+    // Undefined statistics are null rather than errors
+    let (_, values) = Series::from_vec(vec![2.5_f64]).describe();
+    assert_eq!(values.get(1), Some(&2.5));
+    assert_eq!(values.get(2), None);
+    let (_, values) = Series::<i32>::from_vec(vec![]).describe();
+    assert_eq!(values.get(0), Some(&0.));
+    assert_eq!(values.null_count(), 7);
+
+    let words = Series::from_options(vec![
+        Some("b".to_string()),
+        Some("a".to_string()),
+        None,
+        Some("a".to_string()),
+        Some("b".to_string()),
+    ]);
+    let (stats, values) = words.describe();
+    assert_eq!(stats.values, vec!["count", "unique", "top", "freq"]);
+    assert_eq!(values.values, vec!["4", "2", "b", "2"]);
+
+    let (_, values) = Series::<String>::from_vec(vec![]).describe();
+    assert_eq!(values.values[..2], ["0", "0"]);
+    assert_eq!(values.null_count(), 2);
+}
+
+#[test]
+fn test_cov_corr() {
+    let x = Series::from_vec(vec![1_i32, 2, 3, 4, 5, 6]);
+    let y = Series::from_options(vec![
+        Some(2.),
+        Some(1.),
+        Some(f64::NAN),
+        Some(5.),
+        None,
+        Some(5.),
+    ]);
+
+    // Pairs (1, 2), (2, 1), (4, 5) and (6, 5)
+    assert!(x.cov(&y).unwrap().approx_eq(47. / 12., (1e-12, 2)));
+    assert_eq!(y.cov(&x).unwrap(), x.cov(&y).unwrap());
+    let pearson = x.corr(&y, CorrelationMethod::Pearson).unwrap();
+    assert!(pearson.approx_eq(0.8568144142763926, (1e-12, 2)));
+    let spearman = x.corr(&y, CorrelationMethod::Spearman).unwrap();
+    assert!(spearman.approx_eq(0.7378647873726218, (1e-12, 2)));
+    let kendall = x.corr(&y, CorrelationMethod::Kendall).unwrap();
+    assert!(kendall.approx_eq(0.5477225575051661, (1e-12, 2)));
+
+    let reversed = Series::from_vec(vec![6_i32, 5, 4, 3, 2, 1]);
+    for method in [
+        CorrelationMethod::Pearson,
+        CorrelationMethod::Spearman,
+        CorrelationMethod::Kendall,
+    ]
+    .iter()
+    {
+        assert_eq!(x.corr(&reversed, *method).unwrap(), -1.);
+    }
+    assert!(x
+        .corr(
+            &Series::from_vec(vec![1_i32; 6]),
+            CorrelationMethod::Pearson
+        )
+        .unwrap()
+        .is_nan());
+
+    assert!(x.cov(&Series::from_vec(vec![1_i32, 2])).is_err());
+    let sparse = Series::from_options(vec![Some(1.), None, None, None, None, None]);
+    assert!(x.corr(&sparse, CorrelationMethod::Kendall).is_err());
+}
+
+#[test]
+fn test_cut_qcut() {
+    let mut series = Series::from_options(vec![
+        Some(1.),
+        Some(2.5),
+        None,
+        Some(5.),
+        Some(f64::NAN),
+        Some(-3.),
+        Some(7.5),
+        Some(10.),
+    ]);
+    series.set_name("sizes");
+
+    let (binned, edges) = series.cut(&[0., 2.5, 5., 10.], None, true).unwrap();
+    assert_eq!(edges, vec![0., 2.5, 5., 10.]);
+    assert_eq!(binned.name(), Some("sizes".to_string()));
+    assert_eq!(binned.categories(), &["(0, 2.5]", "(2.5, 5]", "(5, 10]"]);
+    assert_eq!(
+        (0..binned.len()).map(|i| binned.get(i)).collect::<Vec<_>>(),
+        vec![
+            Some("(0, 2.5]"),
+            Some("(0, 2.5]"),
+            None,
+            Some("(2.5, 5]"),
+            None,
+            None,
+            Some("(5, 10]"),
+            Some("(5, 10]")
+        ]
+    );
+
+    // Bins closed on the left exclude the last edge
+    let labels = vec!["s".to_string(), "m".to_string(), "l".to_string()];
+    let (binned, _) = series
+        .cut(&[0., 2.5, 5., 10.], Some(labels.clone()), false)
+        .unwrap();
+    assert_eq!(binned.get(1), Some("m"));
+    assert_eq!(binned.get(3), Some("l"));
+    assert_eq!(binned.get(7), None);
+
+    assert!(series.cut(&[0.], None, true).is_err());
+    assert!(series.cut(&[0., 5., 5.], None, true).is_err());
+    assert!(series.cut(&[0., 5.], Some(labels), true).is_err());
+
+    // Equal numbers of values in each quantile, the minimum in the first
+    let (binned, edges) = Series::from_vec(vec![4_i32, 1, 3, 2, 8, 6])
+        .qcut(3)
+        .unwrap();
+    assert_eq!(edges.len(), 4);
+    assert_eq!(edges[0], 1.);
+    assert_eq!(edges[3], 8.);
+    assert_eq!(binned.codes().values, vec![1, 0, 1, 0, 2, 2]);
+
+    assert!(Series::from_vec(vec![1_i32, 1, 1, 2]).qcut(4).is_err());
+    assert!(series.qcut(0).is_err());
+    assert!(Series::<f64>::from_vec(vec![]).qcut(2).is_err());
+}
+
+#[test]
+fn test_isin_between_where_mask() {
+    let series = Series::from_options(vec![Some(1.), None, Some(f64::NAN), Some(4.), Some(6.)]);
+
+    let mask = series.isin(&[4., f64::NAN]);
+    assert_eq!(mask.values, vec![false, false, true, true, false]);
+    assert_eq!(mask.get(1), None);
+
+    let between = |inclusive| series.between(1., 4., inclusive).values;
+    assert_eq!(
+        between(Inclusive::Both),
+        vec![true, false, false, true, false]
+    );
+    assert_eq!(between(Inclusive::Neither), vec![false; 5]);
+    assert_eq!(
+        between(Inclusive::Left),
+        vec![true, false, false, false, false]
+    );
+    assert_eq!(
+        between(Inclusive::Right),
+        vec![false, false, false, true, false]
+    );
+
+    // Null mask elements count as false
+    let mask = Series::from_options(vec![Some(true), Some(true), None, Some(false), Some(true)]);
+    let kept = series.where_(&mask, -1.).unwrap();
+    assert_eq!(kept.get(0), Some(&1.));
+    assert_eq!(kept.get(1), None);
+    assert_eq!(kept.values[2..], [-1., -1., 6.]);
+    let masked = series.mask(&mask, -1.).unwrap();
+    assert_eq!(masked.get(1), Some(&-1.));
+    assert!(masked[2].is_nan());
+    assert_eq!(masked.values[3..], [4., -1.]);
+
+    let words = Series::from_vec(vec!["ab".to_string(), "cd".to_string(), "ef".to_string()]);
+    let other = Series::from_options(vec![None, Some("x".to_string()), Some("y".to_string())]);
+    let mask = words.isin(&["cd".to_string()]);
+    let replaced = words.mask(&mask, &other).unwrap();
+    assert_eq!(replaced.values, vec!["ab", "x", "ef"]);
+    let replaced = words.where_(&mask, &other).unwrap();
+    assert_eq!(replaced.get(0), None);
+    assert_eq!(replaced.get(1), Some(&"cd".to_string()));
+    assert_eq!(
+        words
+            .between("b".to_string(), "e".to_string(), Inclusive::Both)
+            .values,
+        vec![false, true, false]
+    );
+
+    assert!(words
+        .where_(&Series::from_vec(vec![true]), "z".to_string())
+        .is_err());
+    assert!(words
+        .mask(&mask, &Series::from_vec(vec!["z".to_string()]))
+        .is_err());
+}
+
+#[test]
+fn test_nlargest_nsmallest() {
+    let mut series = Series::from_options(vec![
+        Some(4.),
+        None,
+        Some(9.),
+        Some(f64::NAN),
+        Some(1.),
+        Some(9.),
+        Some(-2.),
+    ]);
+    series.set_name("values");
+
+    let (values, positions) = series.nlargest(3);
+    assert_eq!(values.values, vec![9., 9., 4.]);
+    assert_eq!(values.name(), Some("values".to_string()));
+    assert_eq!(positions, vec![2, 5, 0]);
+
+    let (values, positions) = series.nsmallest(2);
+    assert_eq!(values.values, vec![-2., 1.]);
+    assert_eq!(positions, vec![6, 4]);
+
+    // Missing values are never selected, however large `k` is
+    let (values, positions) = series.nsmallest(10);
+    assert_eq!(values.values, vec![-2., 1., 4., 9., 9.]);
+    assert_eq!(positions, vec![6, 4, 0, 2, 5]);
+    assert!(series.nlargest(0).1.is_empty());
+
+    // Matches a full sort on a larger series
+    let big = Series::from_vec((0..1000).map(|i| (i * 7919) % 1009).collect::<Vec<i32>>());
+    let (top, _) = big.nlargest(10);
+    assert_eq!(top.values, big.sort_values(false).values[..10]);
+}
+
+#[test]
+fn test_duplicates() {
+    let mut series = Series::from_options(vec![
+        Some(3.),
+        Some(f64::NAN),
+        None,
+        Some(3.),
+        Some(1.),
+        Some(f64::NAN),
+    ]);
+    series.set_name("values");
+
+    // Missing values, nulls and NaN, are one value
+    let unique = series.unique();
+    assert_eq!(unique.name(), Some("values".to_string()));
+    assert_eq!(unique.len(), 3);
+    assert_eq!(unique[0], 3.);
+    assert!(unique[1].is_nan());
+    assert_eq!(unique[2], 1.);
+    assert_eq!(series.nunique(false), 3);
+    assert_eq!(series.nunique(true), 2);
+
+    assert_eq!(
+        series.duplicated(Keep::First).values,
+        vec![false, false, true, true, false, true]
+    );
+    assert_eq!(
+        series.duplicated(Keep::Last).values,
+        vec![true, true, true, false, false, false]
+    );
+    assert_eq!(
+        series.duplicated(Keep::None).values,
+        vec![true, true, true, true, false, true]
+    );
+
+    let dropped = series.drop_duplicates(Keep::Last);
+    assert_eq!(dropped.values[..2], [3., 1.]);
+    assert!(dropped[2].is_nan());
+    assert_eq!(series.drop_duplicates(Keep::None).values, vec![1.]);
+
+    let words = Series::from_vec(
+        ["b", "a", "b", "c", "a"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    );
+    assert_eq!(words.unique().values, vec!["b", "a", "c"]);
+    assert_eq!(words.drop_duplicates(Keep::None).values, vec!["c"]);
+    assert_eq!(words.nunique(true), 3);
+}
+
+#[test]
+fn test_moments() {
+    let series = Series::from_options(vec![
+        Some(2),
+        Some(8),
+        None,
+        Some(0),
+        Some(4),
+        Some(1),
+        Some(9),
+        Some(9),
+        Some(0),
+    ]);
+
+    assert!(series
+        .skew(true)
+        .unwrap()
+        .approx_eq(0.2650554122698573, (1e-12, 2)));
+    assert!(series
+        .skew(false)
+        .unwrap()
+        .approx_eq(0.33058218040797466, (1e-12, 2)));
+    assert!(series
+        .kurtosis(true)
+        .unwrap()
+        .approx_eq(-1.6660010752838508, (1e-12, 2)));
+    assert!(series
+        .kurtosis(false)
+        .unwrap()
+        .approx_eq(-2.098602258096087, (1e-12, 2)));
+    assert!(series
+        .sem(1.)
+        .unwrap()
+        .approx_eq(1.407093001292279, (1e-12, 2)));
+    assert_eq!(series.mean_abs_dev().unwrap(), 3.40625);
+    assert_eq!(series.median_abs_dev().unwrap(), 3.);
+
+    // Too few values for the sample statistics, or any at all
+    let short = Series::from_vec(vec![1., 5.]);
+    assert!(short.skew(false).is_err());
+    assert_eq!(short.skew(true).unwrap(), 0.);
+    let empty = Series::<f64>::from_vec(vec![]);
+    assert!(empty.skew(true).is_err());
+    assert!(empty.sem(1.).is_err());
+    assert!(empty.mean_abs_dev().is_err());
+    assert!(empty.median_abs_dev().is_err());
+
+    let values = Series::from_vec(vec![1., 5., 2., 5., 10., 6., 4., 6.]);
+    let keys = Series::from_vec(vec![0., 1., 0., 1., 0., 1., 0., 1.]);
+    let grouped = values.groupby(&keys);
+    assert_eq!(grouped.mean_abs_dev().unwrap().values, vec![2.875, 0.5]);
+    assert_eq!(grouped.median_abs_dev().unwrap().values, vec![1.5, 0.5]);
+    assert_eq!(grouped.sem(0.).unwrap().len(), 2);
+    assert!(grouped.skew(false).unwrap()[0] > 0.);
+    assert!(Series::from_vec(vec![1., 2.])
+        .groupby(&Series::from_vec(vec![0., 1.]))
+        .kurtosis(false)
+        .is_err());
+
+    let series = Series::from_vec(vec![1., 2., 10., 4.]);
+    let rolled = series.rolling(3);
+    let skew = rolled.skew(false).unwrap();
+    assert!(skew[1].is_nan());
+    assert!(skew[2].approx_eq(1.6523167403329906, (1e-12, 2)));
+    assert!(skew[3].approx_eq(1.2933427807333961, (1e-12, 2)));
+    assert_eq!(rolled.median_abs_dev().unwrap().values[2..], [1., 2.]);
+    assert!(rolled
+        .kurtosis(false)
+        .unwrap()
+        .values
+        .iter()
+        .all(|v| v.is_nan()));
+    assert_eq!(rolled.kurtosis(true).unwrap().len(), 4);
+    assert_eq!(rolled.sem(0.).unwrap().len(), 4);
+
+    // A NaN only affects the windows containing it, as for `var`
+    let series = Series::from_vec(vec![1., 2., 10., f64::NAN, 4., 6., 5.]);
+    let rolled = series.rolling(3);
+    for stat in [
+        rolled.skew(true).unwrap(),
+        rolled.kurtosis(true).unwrap(),
+        rolled.mean_abs_dev().unwrap(),
+        rolled.median_abs_dev().unwrap(),
+        rolled.var(1.).unwrap(),
+    ] {
+        assert!(stat.values[3..6].iter().all(|v| v.is_nan()));
+        assert!(!stat[2].is_nan() && !stat[6].is_nan());
+    }
+
+    // As does a null, rather than counting its placeholder
+    let series = Series::from_options(vec![Some(1), None, Some(3), Some(5)]);
+    let rolled = series.rolling(2).mean_abs_dev().unwrap();
+    assert!(rolled.values[..3].iter().all(|v| v.is_nan()));
+    assert_eq!(rolled[3], 1.);
+    assert!(series.rolling(2).skew(true).unwrap()[2].is_nan());
+}